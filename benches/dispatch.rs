@@ -0,0 +1,122 @@
+//! Compares selector-table dispatch with per-parent dispatch, which was used before it (and is
+//! still used for generic interfaces), on the same interface tree.
+//! Methods have no arguments, so only routing is measured, not argument decoding.
+//!
+//! Run with `cargo bench --bench dispatch`
+
+use std::{hint::black_box, time::Instant};
+
+use evm_coder::{abi, dummy_contract, solidity_interface, types::*, Call};
+type Result<T> = core::result::Result<T, String>;
+
+pub struct Token;
+dummy_contract! {
+	macro_rules! Token_result {...}
+	impl Contract for Token {...}
+}
+
+macro_rules! parent {
+	($name:ident, $($method:ident)*) => {
+		#[solidity_interface(name = $name)]
+		impl Token {
+			$(
+				fn $method(&self) -> Result<()> {
+					Ok(())
+				}
+			)*
+		}
+	};
+}
+
+// Interface tree, similar to NFT collection with 8 parents
+parent!(Parts, root_a root_b root_c);
+parent!(P1, one_a one_b one_c one_d one_e one_f);
+parent!(P2, two_a two_b two_c two_d two_e two_f);
+parent!(P3, three_a three_b three_c three_d three_e three_f);
+parent!(P4, four_a four_b four_c four_d four_e four_f);
+parent!(P5, five_a five_b five_c five_d five_e five_f);
+parent!(P6, six_a six_b six_c six_d six_e six_f);
+parent!(P7, seven_a seven_b seven_c seven_d seven_e seven_f);
+parent!(P8, eight_a eight_b eight_c eight_d eight_e eight_f);
+
+#[solidity_interface(name = Root, is(P1, P2, P3, P4, P5, P6, P7, P8), inline_is(Parts))]
+impl Token {}
+
+/// Dispatch of the same tree, made by asking every parent in turn, as it was done before
+/// selector table was introduced, and as it is still done for generic interfaces
+fn parse_chain(method_id: Bytes4, reader: &[u8]) -> abi::Result<Option<RootCall>> {
+	if let Some(parsed_call) = RootCall::parse_own(method_id, reader)? {
+		return Ok(Some(parsed_call));
+	}
+	macro_rules! chain {
+		($($parent:ident => $call:ident),*) => {$(
+			if let Some(parsed_call) = $call::parse_own(method_id, reader)? {
+				return Ok(Some(RootCall::$parent(parsed_call)));
+			}
+		)*};
+	}
+	chain!(
+		Parts => PartsCall,
+		P1 => P1Call,
+		P2 => P2Call,
+		P3 => P3Call,
+		P4 => P4Call,
+		P5 => P5Call,
+		P6 => P6Call,
+		P7 => P7Call,
+		P8 => P8Call
+	);
+	Ok(None)
+}
+
+const ITERATIONS: u32 = 200_000;
+
+fn bench(
+	name: &str,
+	parse: fn(Bytes4, &[u8]) -> abi::Result<Option<RootCall>>,
+	selectors: &[Bytes4],
+	input: &[u8],
+) {
+	let start = Instant::now();
+	for _ in 0..ITERATIONS {
+		for selector in selectors {
+			black_box(parse(black_box(*selector), black_box(input)).unwrap());
+		}
+	}
+	let per_call = start.elapsed() / (ITERATIONS * selectors.len() as u32);
+	println!("{name}: {per_call:?} per call");
+}
+
+fn main() {
+	let selectors = (0..RootCall::SELECTOR_COUNT)
+		.map(RootCall::selector_at)
+		.collect::<Vec<_>>();
+	let input = Vec::from([0; 64]);
+	println!(
+		"{} selectors, {} parents",
+		selectors.len(),
+		RootCall::DESCRIPTOR.parents.len() + RootCall::DESCRIPTOR.inline_parents.len()
+	);
+	for selector in &selectors {
+		assert_eq!(
+			RootCall::parse(*selector, &input).unwrap().is_some(),
+			parse_chain(*selector, &input).unwrap().is_some()
+		);
+	}
+	bench("selector table", RootCall::parse, &selectors, &input);
+	bench("parent chain", parse_chain, &selectors, &input);
+	// Unknown selector probes every parent in chain
+	let unknown = [BytesFixed([0xff; 4])];
+	bench(
+		"selector table, unknown selector",
+		RootCall::parse,
+		&unknown,
+		&input,
+	);
+	bench(
+		"parent chain, unknown selector",
+		parse_chain,
+		&unknown,
+		&input,
+	);
+}
@@ -1,4 +1,5 @@
 use quote::quote;
+use syn::spanned::Spanned;
 
 use super::extract_docs;
 
@@ -6,25 +7,73 @@ pub fn impl_solidity_option<'a>(
 	docs: &[String],
 	name: &proc_macro2::Ident,
 	enum_options: impl Iterator<Item = &'a syn::Variant> + Clone,
+	repr: &syn::Ident,
+	values: &[u32],
 ) -> proc_macro2::TokenStream {
+	// Solidity enums can't have gaps, and are always encoded as uint8,
+	// other enums are represented with constants library
+	let is_sparse = repr != "u8" || values.iter().enumerate().any(|(i, v)| *v as usize != i);
+	let option_prefix = if is_sparse {
+		format!("{name}Lib")
+	} else {
+		name.to_string()
+	};
 	let variant_names = enum_options.clone().map(|opt| {
 		let opt = &opt.ident;
-		let s = name.to_string() + "." + opt.to_string().as_str();
+		let s = option_prefix.clone() + "." + opt.to_string().as_str();
 		let as_string = proc_macro2::Literal::string(s.as_str());
 		quote!(#name::#opt => #as_string,)
 	});
 	let solidity_name = name.to_string();
 
-	let solidity_fields = enum_options.map(|v| {
-		let docs = extract_docs(&v.attrs).expect("TODO: handle bad docs");
-		let name = v.ident.to_string();
+	let interface = if is_sparse {
+		let solidity_fields = enum_options.zip(values).map(|(v, value)| {
+			let docs = extract_docs(&v.attrs).expect("TODO: handle bad docs");
+			let name = v.ident.to_string();
+			quote! {
+				SolidityEnumConstant {
+					docs: &[#(#docs),*],
+					name: #name,
+					value: #value,
+				}
+			}
+		});
+		let ty = format!("uint{}", &repr.to_string()[1..]);
 		quote! {
-			SolidityEnumVariant {
+			let interface = SolidityEnumLibrary {
 				docs: &[#(#docs),*],
-				name: #name,
+				name: #solidity_name,
+				ty: #ty,
+				fields: &[#(
+					#solidity_fields,
+				)*],
+			};
+			let mut out = String::new();
+			let _ = interface.format(&mut out);
+		}
+	} else {
+		let solidity_fields = enum_options.map(|v| {
+			let docs = extract_docs(&v.attrs).expect("TODO: handle bad docs");
+			let name = v.ident.to_string();
+			quote! {
+				SolidityEnumVariant {
+					docs: &[#(#docs),*],
+					name: #name,
+				}
 			}
+		});
+		quote! {
+			let interface = SolidityEnum {
+				docs: &[#(#docs),*],
+				name: #solidity_name,
+				fields: &[#(
+					#solidity_fields,
+				)*],
+			};
+			let mut out = String::new();
+			let _ = interface.format(&mut out, tc);
 		}
-	});
+	};
 
 	quote!(
 		#[cfg(feature = "stubgen")]
@@ -32,15 +81,7 @@ pub fn impl_solidity_option<'a>(
 			fn generate_solidity_interface(tc: &evm_coder::solidity::TypeCollector) -> String {
 				use evm_coder::solidity::*;
 				use core::fmt::Write;
-				let interface = SolidityEnum {
-					docs: &[#(#docs),*],
-					name: #solidity_name,
-					fields: &[#(
-						#solidity_fields,
-					)*],
-				};
-				let mut out = String::new();
-				let _ = interface.format(&mut out, tc);
+				#interface
 				tc.collect(out);
 				#solidity_name.to_string()
 			}
@@ -53,23 +94,25 @@ pub fn impl_solidity_option<'a>(
 	)
 }
 
-pub fn impl_enum_from_u8<'a>(
+pub fn impl_enum_from_repr<'a>(
 	name: &proc_macro2::Ident,
 	enum_options: impl Iterator<Item = &'a syn::Variant>,
+	repr: &syn::Ident,
+	values: &[u32],
 ) -> proc_macro2::TokenStream {
 	let error_str = format!("value not convertible into enum \"{name}\"");
 	let error_str = proc_macro2::Literal::string(&error_str);
-	let enum_options = enum_options.enumerate().map(|(i, opt)| {
+	let enum_options = enum_options.zip(values).map(|(opt, value)| {
 		let opt = &opt.ident;
-		let n = proc_macro2::Literal::u8_suffixed(u8::try_from(i).expect("checked can cast"));
+		let n = proc_macro2::Literal::u32_unsuffixed(*value);
 		quote! {#n => Ok(#name::#opt),}
 	});
 
 	quote!(
-		impl TryFrom<u8> for #name {
+		impl TryFrom<#repr> for #name {
 			type Error = &'static str;
 
-			fn try_from(value: u8) -> ::core::result::Result<Self, Self::Error> {
+			fn try_from(value: #repr) -> ::core::result::Result<Self, Self::Error> {
 				const err: &'static str = #error_str;
 				match value {
 					#(#enum_options)*
@@ -80,22 +123,22 @@ pub fn impl_enum_from_u8<'a>(
 	)
 }
 
-pub fn impl_enum_abi_type(name: &syn::Ident) -> proc_macro2::TokenStream {
+pub fn impl_enum_abi_type(name: &syn::Ident, repr: &syn::Ident) -> proc_macro2::TokenStream {
 	quote! {
 		impl ::evm_coder::abi::AbiType for #name {
-			const SIGNATURE: ::evm_coder::custom_signature::SignatureUnit = <u8 as ::evm_coder::abi::AbiType>::SIGNATURE;
-			const IS_DYNAMIC: bool = <u8 as ::evm_coder::abi::AbiType>::IS_DYNAMIC;
-			const HEAD_WORDS: u32 = <u8 as ::evm_coder::abi::AbiType>::HEAD_WORDS;
+			const SIGNATURE: ::evm_coder::custom_signature::SignatureUnit = <#repr as ::evm_coder::abi::AbiType>::SIGNATURE;
+			const IS_DYNAMIC: bool = <#repr as ::evm_coder::abi::AbiType>::IS_DYNAMIC;
+			const HEAD_WORDS: u32 = <#repr as ::evm_coder::abi::AbiType>::HEAD_WORDS;
 		}
 	}
 }
 
-pub fn impl_enum_abi_read(name: &syn::Ident) -> proc_macro2::TokenStream {
+pub fn impl_enum_abi_read(name: &syn::Ident, repr: &syn::Ident) -> proc_macro2::TokenStream {
 	quote!(
 		impl ::evm_coder::abi::AbiDecode for #name {
 			fn dec(reader: &mut ::evm_coder::abi::AbiDecoder) -> ::evm_coder::abi::Result<Self> {
 				Ok(
-					<u8 as ::evm_coder::abi::AbiDecode>::dec(reader)?
+					<#repr as ::evm_coder::abi::AbiDecode>::dec(reader)?
 						.try_into()?
 				)
 			}
@@ -103,11 +146,32 @@ pub fn impl_enum_abi_read(name: &syn::Ident) -> proc_macro2::TokenStream {
 	)
 }
 
-pub fn impl_enum_abi_write(name: &syn::Ident) -> proc_macro2::TokenStream {
+pub fn impl_enum_abi_write(name: &syn::Ident, repr: &syn::Ident) -> proc_macro2::TokenStream {
 	quote!(
 		impl ::evm_coder::abi::AbiEncode for #name {
 			fn enc(&self, writer: &mut ::evm_coder::abi::AbiEncoder) {
-				::evm_coder::abi::AbiEncode::enc(&(*self as u8), writer);
+				::evm_coder::abi::AbiEncode::enc(&(*self as #repr), writer);
+			}
+		}
+	)
+}
+
+/// Enums without fields are value types, stored in topics as is
+pub fn impl_enum_topic(name: &syn::Ident, repr: &syn::Ident) -> proc_macro2::TokenStream {
+	quote!(
+		impl ::evm_coder::events::ToTopic for #name {
+			fn to_topic(&self) -> ::evm_coder::types::Topic {
+				::evm_coder::events::ToTopic::to_topic(&(*self as #repr))
+			}
+		}
+		impl ::evm_coder::events::FromTopic for #name {
+			fn from_topic(topic: &::evm_coder::types::Topic) -> ::evm_coder::abi::Result<Self> {
+				Ok(<#repr as ::evm_coder::events::FromTopic>::from_topic(topic)?.try_into()?)
+			}
+		}
+		impl ::evm_coder::events::EncodeInPlace for #name {
+			fn encode_in_place(&self, out: &mut ::evm_coder::types::Vec<u8>) {
+				::evm_coder::events::EncodeInPlace::encode_in_place(&(*self as #repr), out)
 			}
 		}
 	)
@@ -138,59 +202,81 @@ pub fn impl_enum_solidity_type_name(name: &syn::Ident) -> proc_macro2::TokenStre
 	)
 }
 
-pub fn check_enum_fields(de: &syn::DataEnum) -> syn::Result<()> {
-	for v in &de.variants {
-		if !v.fields.is_empty() {
-			return Err(syn::Error::new(
-				v.ident.span(),
-				"Enumeration parameters should not have fields",
-			));
-		} else if v.discriminant.is_some() {
-			return Err(syn::Error::new(
-				v.ident.span(),
-				"Enumeration options should not have an explicit specified value",
-			));
-		}
-	}
-
-	Ok(())
+/// Values of enum options, either explicitly specified, or following the previous option value
+pub fn enum_values(de: &syn::DataEnum, repr: &syn::Ident) -> syn::Result<Vec<u32>> {
+	let max = match repr.to_string().as_str() {
+		"u8" => u32::from(u8::MAX),
+		"u16" => u32::from(u16::MAX),
+		_ => u32::MAX,
+	};
+	let mut next = Some(0u32);
+	de.variants
+		.iter()
+		.map(|v| {
+			let value = match &v.discriminant {
+				Some((
+					_,
+					syn::Expr::Lit(syn::ExprLit {
+						lit: syn::Lit::Int(value),
+						..
+					}),
+				)) => value.base10_parse::<u32>()?,
+				Some((_, expr)) => {
+					return Err(syn::Error::new(
+						expr.span(),
+						"Enumeration option value should be an integer literal",
+					))
+				}
+				None => next.ok_or_else(|| {
+					syn::Error::new(
+						v.ident.span(),
+						format!("Enumeration option value overflows {repr}"),
+					)
+				})?,
+			};
+			if value > max {
+				return Err(syn::Error::new(
+					v.ident.span(),
+					format!("Enumeration option value overflows {repr}"),
+				));
+			}
+			next = value.checked_add(1);
+			Ok(value)
+		})
+		.collect()
 }
 
-pub fn check_repr_u8(name: &syn::Ident, attrs: &[syn::Attribute]) -> syn::Result<()> {
-	let mut has_repr = false;
+const REPR_ERROR: &str = "Enum is not \"repr(u8)\", \"repr(u16)\" or \"repr(u32)\"";
+
+/// Integer type, used as enum layout, and as its ABI representation
+pub fn parse_repr(name: &syn::Ident, attrs: &[syn::Attribute]) -> syn::Result<syn::Ident> {
+	let mut repr = None;
 	for attr in attrs {
 		if attr.path.is_ident("repr") {
-			has_repr = true;
 			let meta = attr.parse_meta()?;
-			check_meta_u8(&meta)?;
+			if let Some(ident) = parse_meta_repr(&meta)? {
+				repr = Some(ident);
+			}
 		}
 	}
 
-	if !has_repr {
-		return Err(syn::Error::new(name.span(), "Enum is not \"repr(u8)\""));
-	}
-
-	Ok(())
+	repr.ok_or_else(|| syn::Error::new(name.span(), REPR_ERROR))
 }
 
-fn check_meta_u8(meta: &syn::Meta) -> Result<(), syn::Error> {
+fn parse_meta_repr(meta: &syn::Meta) -> Result<Option<syn::Ident>, syn::Error> {
+	let mut repr = None;
 	if let syn::Meta::List(p) = meta {
 		for nm in &p.nested {
 			if let syn::NestedMeta::Meta(syn::Meta::Path(p)) = nm {
-				if !p.is_ident("u8") {
-					return Err(syn::Error::new(
-						p.segments
-							.first()
-							.expect("repr segments are empty")
-							.ident
-							.span(),
-						"Enum is not \"repr(u8)\"",
-					));
+				let ident = &p.segments.first().expect("repr segments are empty").ident;
+				if !(ident == "u8" || ident == "u16" || ident == "u32") {
+					return Err(syn::Error::new(ident.span(), REPR_ERROR));
 				}
+				repr = Some(ident.clone());
 			}
 		}
 	}
-	Ok(())
+	Ok(repr)
 }
 
 pub fn expand_enum(
@@ -198,16 +284,23 @@ pub fn expand_enum(
 	ast: &syn::DeriveInput,
 ) -> syn::Result<proc_macro2::TokenStream> {
 	let name = &ast.ident;
-	check_repr_u8(name, &ast.attrs)?;
-	check_enum_fields(de)?;
+	if !ast.generics.params.is_empty() {
+		return Err(syn::Error::new(
+			ast.generics.span(),
+			"Enums without fields can't have generic parameters",
+		));
+	}
+	let repr = parse_repr(name, &ast.attrs)?;
+	let values = enum_values(de, &repr)?;
 	let docs = extract_docs(&ast.attrs)?;
 	let enum_options = de.variants.iter();
 
-	let from = impl_enum_from_u8(name, enum_options.clone());
-	let solidity_option = impl_solidity_option(&docs, name, enum_options.clone());
-	let abi_type = impl_enum_abi_type(name);
-	let abi_read = impl_enum_abi_read(name);
-	let abi_write = impl_enum_abi_write(name);
+	let from = impl_enum_from_repr(name, enum_options.clone(), &repr, &values);
+	let solidity_option = impl_solidity_option(&docs, name, enum_options.clone(), &repr, &values);
+	let abi_type = impl_enum_abi_type(name, &repr);
+	let abi_read = impl_enum_abi_read(name, &repr);
+	let abi_write = impl_enum_abi_write(name, &repr);
+	let topic = impl_enum_topic(name, &repr);
 	let solidity_type_name = impl_enum_solidity_type_name(name);
 
 	Ok(quote! {
@@ -216,6 +309,7 @@ pub fn expand_enum(
 		#abi_type
 		#abi_read
 		#abi_write
+		#topic
 		#solidity_type_name
 	})
 }
@@ -82,6 +82,27 @@ pub fn impl_struct_abi_write(name: &syn::Ident, total_bytes: usize) -> syn::Resu
 	))
 }
 
+/// Flags are value types, stored in topics as is
+pub fn impl_struct_topic(name: &syn::Ident) -> TokenStream {
+	quote! {
+		impl ::evm_coder::events::ToTopic for #name {
+			fn to_topic(&self) -> ::evm_coder::types::Topic {
+				::evm_coder::events::word_topic(self)
+			}
+		}
+		impl ::evm_coder::events::FromTopic for #name {
+			fn from_topic(topic: &::evm_coder::types::Topic) -> ::evm_coder::abi::Result<Self> {
+				<Self as ::evm_coder::abi::AbiDecode>::abi_decode(&topic.0)
+			}
+		}
+		impl ::evm_coder::events::EncodeInPlace for #name {
+			fn encode_in_place(&self, out: &mut ::evm_coder::types::Vec<u8>) {
+				out.extend_from_slice(&::evm_coder::abi::AbiEncode::abi_encode(self));
+			}
+		}
+	}
+}
+
 pub fn impl_struct_solidity_type<'a>(
 	name: &syn::Ident,
 	docs: &[String],
@@ -221,6 +242,7 @@ pub fn expand_flags(ds: &syn::DataStruct, ast: &syn::DeriveInput) -> syn::Result
 	let abi_type = impl_struct_abi_type(name, total_bytes)?;
 	let abi_read = impl_struct_abi_read(name, total_bytes)?;
 	let abi_write = impl_struct_abi_write(name, total_bytes)?;
+	let topic = impl_struct_topic(name);
 	let solidity_type =
 		impl_struct_solidity_type(name, &docs, total_bytes, struct_info.fields.iter())?;
 	let solidity_type_name = impl_struct_solidity_type_name(name);
@@ -228,6 +250,7 @@ pub fn expand_flags(ds: &syn::DataStruct, ast: &syn::DeriveInput) -> syn::Result
 		#abi_type
 		#abi_read
 		#abi_write
+		#topic
 		#solidity_type
 		#solidity_type_name
 	})
@@ -1,92 +1,174 @@
 use proc_macro2::TokenStream;
-use quote::quote;
-use syn::Field;
+use quote::{format_ident, quote};
+use syn::{parse::ParseStream, spanned::Spanned, LitStr, Token};
 
-use super::extract_docs;
+use super::{
+	bounded_generics, check_generics, extract_docs, optional_bounded_generics, solidity_generics,
+	solidity_struct_name,
+};
 
-pub fn tuple_type<'a>(field_types: impl Iterator<Item = &'a syn::Type> + Clone) -> TokenStream {
-	let field_types = field_types.map(|ty| quote!(#ty,));
-	quote! {(#(#field_types)*)}
+mod kw {
+	syn::custom_keyword!(skip);
+	syn::custom_keyword!(rename);
+	syn::custom_keyword!(with);
+	syn::custom_keyword!(bytes);
 }
 
-pub fn tuple_ref_type<'a>(field_types: impl Iterator<Item = &'a syn::Type> + Clone) -> TokenStream {
-	let field_types = field_types.map(|ty| quote!(&#ty,));
-	quote! {(#(#field_types)*)}
+/// Field attributes, specified as `#[abi(...)]`
+#[derive(Default)]
+struct FieldInfo {
+	skip: bool,
+	rename: Option<String>,
+	with: Option<syn::Path>,
 }
+impl FieldInfo {
+	/// Parse contents of single `#[abi(...)]` attribute, field may have multiple of them
+	fn parse_into(&mut self, input: ParseStream) -> syn::Result<()> {
+		while !input.is_empty() {
+			let lookahead = input.lookahead1();
+			if lookahead.peek(kw::skip) {
+				input.parse::<kw::skip>()?;
+				self.skip = true;
+			} else if lookahead.peek(kw::rename) {
+				let k = input.parse::<kw::rename>()?;
+				input.parse::<Token![=]>()?;
+				if self
+					.rename
+					.replace(input.parse::<LitStr>()?.value())
+					.is_some()
+				{
+					return Err(syn::Error::new(k.span, "rename is already set"));
+				}
+			} else if lookahead.peek(kw::with) {
+				let k = input.parse::<kw::with>()?;
+				input.parse::<Token![=]>()?;
+				if self.with.replace(input.parse()?).is_some() {
+					return Err(syn::Error::new(k.span, "with is already set"));
+				}
+			} else if lookahead.peek(kw::bytes) {
+				let k = input.parse::<kw::bytes>()?;
+				if self
+					.with
+					.replace(syn::parse_quote!(::evm_coder::abi::with::bytes))
+					.is_some()
+				{
+					return Err(syn::Error::new(k.span, "with is already set"));
+				}
+			} else {
+				return Err(lookahead.error());
+			}
 
-pub fn tuple_data_as_ref(
-	is_named_fields: bool,
-	field_names: impl Iterator<Item = syn::Ident> + Clone,
-) -> TokenStream {
-	let field_names = field_names.enumerate().map(|(i, field)| {
-		if is_named_fields {
-			quote!(&self.#field,)
-		} else {
-			let field = proc_macro2::Literal::usize_unsuffixed(i);
-			quote!(&self.#field,)
+			if input.peek(Token![,]) {
+				input.parse::<Token![,]>()?;
+			} else if !input.is_empty() {
+				return Err(syn::Error::new(input.span(), "expected end"));
+			}
 		}
-	});
-	quote! {(#(#field_names)*)}
+		Ok(())
+	}
 }
 
-pub fn tuple_names(
-	is_named_fields: bool,
-	field_names: impl Iterator<Item = syn::Ident> + Clone,
-) -> TokenStream {
-	let field_names = field_names.enumerate().map(|(i, field)| {
-		if is_named_fields {
-			quote!(#field,)
-		} else {
-			let field = proc_macro2::Ident::new(
-				format!("field{i}").as_str(),
-				proc_macro2::Span::call_site(),
-			);
-			quote!(#field,)
-		}
-	});
-	quote! {(#(#field_names)*)}
+pub struct StructField {
+	member: syn::Member,
+	binding: syn::Ident,
+	ty: syn::Type,
+	docs: Vec<String>,
+	solidity_name: String,
+	skip: bool,
+	with: Option<syn::Path>,
 }
+impl StructField {
+	fn try_from(i: usize, field: &syn::Field) -> syn::Result<Self> {
+		let mut info = FieldInfo::default();
+		for attr in &field.attrs {
+			if attr.path.is_ident("abi") {
+				attr.parse_args_with(|input: ParseStream| info.parse_into(input))?;
+			}
+		}
+		if info.skip && (info.rename.is_some() || info.with.is_some()) {
+			return Err(syn::Error::new(
+				field.ty.span(),
+				"skipped field can't have other abi attributes",
+			));
+		}
+		let (member, binding) = match &field.ident {
+			Some(ident) => (syn::Member::Named(ident.clone()), ident.clone()),
+			None => (syn::Member::Unnamed(i.into()), format_ident!("field{i}")),
+		};
+		let solidity_name = info.rename.unwrap_or_else(|| {
+			field
+				.ident
+				.as_ref()
+				.map_or_else(|| format!("field_{i}"), ToString::to_string)
+		});
+		Ok(Self {
+			member,
+			binding,
+			ty: field.ty.clone(),
+			docs: extract_docs(&field.attrs)?,
+			solidity_name,
+			skip: info.skip,
+			with: info.with,
+		})
+	}
 
-pub fn struct_from_tuple(
-	name: &syn::Ident,
-	is_named_fields: bool,
-	field_names: impl Iterator<Item = syn::Ident> + Clone,
-) -> TokenStream {
-	let field_names = field_names.enumerate().map(|(i, field)| {
-		if is_named_fields {
-			quote!(#field,)
-		} else {
-			let field = proc_macro2::Ident::new(
-				format!("field{i}").as_str(),
-				proc_macro2::Span::call_site(),
-			);
-			quote!(#field,)
+	/// Type, used to represent this field in ABI
+	fn abi_type(&self) -> TokenStream {
+		match &self.with {
+			Some(with) => quote! {#with::Abi},
+			None => {
+				let ty = &self.ty;
+				quote! {#ty}
+			}
 		}
-	});
+	}
 
-	if is_named_fields {
-		quote! {#name {#(#field_names)*}}
-	} else {
-		quote! {#name (#(#field_names)*)}
+	fn abi_ref_value(&self) -> TokenStream {
+		let member = &self.member;
+		match &self.with {
+			Some(with) => quote! {#with::encode(&self.#member)},
+			None => quote! {&self.#member},
+		}
 	}
-}
 
-pub fn map_field_to_name(field: (usize, &syn::Field)) -> syn::Ident {
-	if let Some(name) = field.1.ident.as_ref() {
-		return name.clone();
+	/// Value, used to encode field in topic, `with` fields are converted to `Abi` type, which
+	/// is bound to implement topic encoding
+	fn topic_value(&self) -> TokenStream {
+		let member = &self.member;
+		match &self.with {
+			Some(with) => quote! {
+				::evm_coder::events::abi_convert::<_, #with::Abi>(&#with::encode(&self.#member))
+			},
+			None => quote! {&self.#member},
+		}
 	}
-	let mut name = "field".to_string();
-	name.push_str(field.0.to_string().as_str());
-	syn::Ident::new(name.as_str(), proc_macro2::Span::call_site())
-}
 
-pub fn map_field_to_type(field: &syn::Field) -> &syn::Type {
-	&field.ty
+	fn decoded_value(&self) -> TokenStream {
+		let binding = &self.binding;
+		if self.skip {
+			quote! {::core::default::Default::default()}
+		} else if let Some(with) = &self.with {
+			quote! {#with::decode(#binding)?}
+		} else {
+			quote! {#binding}
+		}
+	}
 }
 
-pub fn impl_struct_abi_type(name: &syn::Ident, tuple_type: &TokenStream) -> TokenStream {
+pub fn impl_struct_abi_type(
+	name: &syn::Ident,
+	generics: &syn::Generics,
+	tuple_type: &TokenStream,
+	fields: &[StructField],
+) -> TokenStream {
+	let generics = bounded_generics(
+		generics,
+		fields.iter().filter(|f| !f.skip).map(StructField::abi_type),
+		&quote!(::evm_coder::abi::AbiType),
+	);
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 	quote! {
-		impl ::evm_coder::abi::AbiType for #name {
+		impl #impl_generics ::evm_coder::abi::AbiType for #name #ty_generics #where_clause {
 			const SIGNATURE: ::evm_coder::custom_signature::SignatureUnit = <#tuple_type as ::evm_coder::abi::AbiType>::SIGNATURE;
 			const IS_DYNAMIC: bool = <#tuple_type as ::evm_coder::abi::AbiType>::IS_DYNAMIC;
 			const HEAD_WORDS: u32 = <#tuple_type as ::evm_coder::abi::AbiType>::HEAD_WORDS;
@@ -96,15 +178,28 @@ pub fn impl_struct_abi_type(name: &syn::Ident, tuple_type: &TokenStream) -> Toke
 
 pub fn impl_struct_abi_read(
 	name: &syn::Ident,
+	generics: &syn::Generics,
 	tuple_type: &TokenStream,
-	tuple_names: &TokenStream,
-	struct_from_tuple: &TokenStream,
+	fields: &[StructField],
 ) -> TokenStream {
+	let generics = bounded_generics(
+		generics,
+		fields.iter().filter(|f| !f.skip).map(StructField::abi_type),
+		&quote!(::evm_coder::abi::AbiDecode),
+	);
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	let tuple_names = fields.iter().filter(|f| !f.skip).map(|f| &f.binding);
+	let members = fields.iter().map(|f| &f.member);
+	let values = fields.iter().map(StructField::decoded_value);
 	quote!(
-		impl ::evm_coder::abi::AbiDecode for #name {
+		impl #impl_generics ::evm_coder::abi::AbiDecode for #name #ty_generics #where_clause {
 			fn dec(reader: &mut ::evm_coder::abi::AbiDecoder) -> ::evm_coder::abi::Result<Self> {
-				let #tuple_names = <#tuple_type as ::evm_coder::abi::AbiDecode>::dec(reader)?;
-				Ok(#struct_from_tuple)
+				let (#(#tuple_names,)*) = <#tuple_type as ::evm_coder::abi::AbiDecode>::dec(reader)?;
+				Ok(Self {
+					#(
+						#members: #values,
+					)*
+				})
 			}
 		}
 	)
@@ -112,32 +207,71 @@ pub fn impl_struct_abi_read(
 
 pub fn impl_struct_abi_write(
 	name: &syn::Ident,
-	_is_named_fields: bool,
-	tuple_type: &TokenStream,
-	tuple_data: &TokenStream,
+	generics: &syn::Generics,
+	fields: &[StructField],
 ) -> TokenStream {
+	let fields = fields.iter().filter(|f| !f.skip);
+	let generics = bounded_generics(
+		generics,
+		fields.clone().map(StructField::abi_type),
+		&quote!(::evm_coder::abi::AbiEncode),
+	);
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	let tuple_data = fields.map(StructField::abi_ref_value);
 	quote!(
-		impl ::evm_coder::abi::AbiEncode for #name {
+		impl #impl_generics ::evm_coder::abi::AbiEncode for #name #ty_generics #where_clause {
 			fn enc(&self, writer: &mut ::evm_coder::abi::AbiEncoder) {
-				<#tuple_type as ::evm_coder::abi::AbiEncode>::enc(&#tuple_data, writer)
+				::evm_coder::abi::AbiEncode::enc(&(#(#tuple_data,)*), writer)
 			}
 		}
 	)
 }
 
-pub fn impl_struct_solidity_type<'a>(
+/// Structs are reference types, stored in topics as hash
+pub fn impl_struct_topic(
 	name: &syn::Ident,
+	generics: &syn::Generics,
+	fields: &[StructField],
+) -> TokenStream {
+	let fields = fields.iter().filter(|f| !f.skip);
+	let generics = optional_bounded_generics(
+		generics,
+		fields.clone().map(StructField::abi_type),
+		&quote!(::evm_coder::events::EncodeInPlace),
+	);
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	let tuple_data = fields.map(StructField::topic_value);
+	quote! {
+		impl #impl_generics ::evm_coder::events::EncodeInPlace for #name #ty_generics #where_clause {
+			fn encode_in_place(&self, out: &mut ::evm_coder::types::Vec<u8>) {
+				::evm_coder::events::EncodeInPlace::encode_in_place(&(#(#tuple_data,)*), out)
+			}
+		}
+		impl #impl_generics ::evm_coder::events::ToTopic for #name #ty_generics #where_clause {
+			fn to_topic(&self) -> ::evm_coder::types::Topic {
+				::evm_coder::events::reference_topic(self)
+			}
+		}
+	}
+}
+
+pub fn impl_struct_solidity_type(
+	name: &syn::Ident,
+	generics: &syn::Generics,
 	docs: &[String],
-	fields: impl Iterator<Item = &'a Field> + Clone,
+	fields: &[StructField],
 ) -> TokenStream {
-	let solidity_name = name.to_string();
-	let solidity_fields = fields.enumerate().map(|(i, f)| {
-		let name = f
-			.ident
-			.as_ref()
-			.map_or_else(|| format!("field_{i}"), ToString::to_string);
-		let ty = &f.ty;
-		let docs = extract_docs(&f.attrs).expect("TODO: handle bad docs");
+	let solidity_name = solidity_struct_name(name, generics);
+	let generics = bounded_generics(
+		&solidity_generics(generics),
+		fields.iter().filter(|f| !f.skip).map(StructField::abi_type),
+		&quote!(::evm_coder::solidity::SolidityTypeName),
+	);
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	let solidity_fields = fields.iter().filter(|f| !f.skip).map(|f| {
+		let name = &f.solidity_name;
+		let ty = f.abi_type();
+		let docs = &f.docs;
 		quote! {
 			SolidityStructField::<#ty> {
 				docs: &[#(#docs),*],
@@ -148,14 +282,15 @@ pub fn impl_struct_solidity_type<'a>(
 	});
 	quote! {
 		#[cfg(feature = "stubgen")]
-		impl ::evm_coder::solidity::SolidityStructTy for #name {
+		impl #impl_generics ::evm_coder::solidity::SolidityStructTy for #name #ty_generics #where_clause {
 			/// Generate solidity definitions for methods described in this struct
 			fn generate_solidity_interface(tc: &evm_coder::solidity::TypeCollector) -> String {
 				use evm_coder::solidity::*;
 				use core::fmt::Write;
+				let solidity_name: String = #solidity_name;
 				let interface = SolidityStruct {
 					docs: &[#(#docs),*],
-					name: #solidity_name,
+					name: solidity_name.as_str(),
 					fields: (#(
 						#solidity_fields,
 					)*),
@@ -163,18 +298,30 @@ pub fn impl_struct_solidity_type<'a>(
 				let mut out = String::new();
 				let _ = interface.format(&mut out, tc);
 				tc.collect(out);
-				#solidity_name.to_string()
+				solidity_name
 			}
 		}
 	}
 }
 
-pub fn impl_struct_solidity_type_name<'a>(
+pub fn impl_struct_solidity_type_name(
 	name: &syn::Ident,
-	field_types: impl Iterator<Item = &'a syn::Type> + Clone,
-	params_count: usize,
+	generics: &syn::Generics,
+	fields: &[StructField],
 ) -> TokenStream {
-	let arg_dafaults = field_types.enumerate().map(|(i, ty)| {
+	let field_types = fields
+		.iter()
+		.filter(|f| !f.skip)
+		.map(StructField::abi_type)
+		.collect::<Vec<_>>();
+	let generics = bounded_generics(
+		&solidity_generics(generics),
+		field_types.iter().cloned(),
+		&quote!(::evm_coder::solidity::SolidityTypeName),
+	);
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	let params_count = field_types.len();
+	let arg_dafaults = field_types.iter().enumerate().map(|(i, ty)| {
 		let mut defult_value = quote!(<#ty as ::evm_coder::solidity::SolidityTypeName
 			>::solidity_default(writer, tc)?;);
 		let last_item = params_count - 1;
@@ -186,7 +333,7 @@ pub fn impl_struct_solidity_type_name<'a>(
 
 	quote! {
 		#[cfg(feature = "stubgen")]
-		impl ::evm_coder::solidity::SolidityTypeName for #name {
+		impl #impl_generics ::evm_coder::solidity::SolidityTypeName for #name #ty_generics #where_clause {
 			fn solidity_name(
 				writer: &mut impl ::core::fmt::Write,
 				tc: &::evm_coder::solidity::TypeCollector,
@@ -217,44 +364,38 @@ pub fn expand_struct(
 	ast: &syn::DeriveInput,
 ) -> syn::Result<proc_macro2::TokenStream> {
 	let name = &ast.ident;
+	let generics = &ast.generics;
 	let docs = extract_docs(&ast.attrs)?;
-	let (is_named_fields, field_names, field_types, params_count) = match ds.fields {
-		syn::Fields::Named(ref fields) => Ok((
-			true,
-			fields.named.iter().enumerate().map(map_field_to_name),
-			fields.named.iter().map(map_field_to_type),
-			fields.named.len(),
-		)),
-		syn::Fields::Unnamed(ref fields) => Ok((
-			false,
-			fields.unnamed.iter().enumerate().map(map_field_to_name),
-			fields.unnamed.iter().map(map_field_to_type),
-			fields.unnamed.len(),
-		)),
-		syn::Fields::Unit => Err(syn::Error::new(name.span(), "Unit structs not supported")),
-	}?;
+	check_generics(generics)?;
+	if let syn::Fields::Unit = ds.fields {
+		return Err(syn::Error::new(name.span(), "Unit structs not supported"));
+	}
+	let fields = ds
+		.fields
+		.iter()
+		.enumerate()
+		.map(|(i, f)| StructField::try_from(i, f))
+		.collect::<syn::Result<Vec<_>>>()?;
 
-	if params_count == 0 {
+	if fields.iter().all(|f| f.skip) {
 		return Err(syn::Error::new(name.span(), "Empty structs not supported"));
 	};
 
-	let tuple_type = tuple_type(field_types.clone());
-	let tuple_ref_type = tuple_ref_type(field_types.clone());
-	let tuple_data = tuple_data_as_ref(is_named_fields, field_names.clone());
-	let tuple_names = tuple_names(is_named_fields, field_names.clone());
-	let struct_from_tuple = struct_from_tuple(name, is_named_fields, field_names.clone());
+	let field_types = fields.iter().filter(|f| !f.skip).map(StructField::abi_type);
+	let tuple_type = quote! {(#(#field_types,)*)};
 
-	let abi_type = impl_struct_abi_type(name, &tuple_type);
-	let abi_read = impl_struct_abi_read(name, &tuple_type, &tuple_names, &struct_from_tuple);
-	let abi_write = impl_struct_abi_write(name, is_named_fields, &tuple_ref_type, &tuple_data);
-	let solidity_type = impl_struct_solidity_type(name, &docs, ds.fields.iter());
-	let solidity_type_name =
-		impl_struct_solidity_type_name(name, field_types.clone(), params_count);
+	let abi_type = impl_struct_abi_type(name, generics, &tuple_type, &fields);
+	let abi_read = impl_struct_abi_read(name, generics, &tuple_type, &fields);
+	let abi_write = impl_struct_abi_write(name, generics, &fields);
+	let topic = impl_struct_topic(name, generics, &fields);
+	let solidity_type = impl_struct_solidity_type(name, generics, &docs, &fields);
+	let solidity_type_name = impl_struct_solidity_type_name(name, generics, &fields);
 
 	Ok(quote! {
 		#abi_type
 		#abi_read
 		#abi_write
+		#topic
 		#solidity_type
 		#solidity_type_name
 	})
@@ -0,0 +1,428 @@
+use inflector::cases;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use super::{
+	bounded_generics, check_generics, extract_docs, optional_bounded_generics, solidity_generics,
+	solidity_struct_name,
+};
+
+struct TaggedVariant {
+	ident: syn::Ident,
+	docs: Vec<String>,
+	kind: u8,
+	is_named_fields: bool,
+	field_members: Vec<syn::Member>,
+	field_names: Vec<syn::Ident>,
+	field_types: Vec<syn::Type>,
+	solidity_name: String,
+}
+
+impl TaggedVariant {
+	fn try_from(kind: usize, variant: &syn::Variant) -> syn::Result<Self> {
+		if variant.discriminant.is_some() {
+			return Err(syn::Error::new(
+				variant.ident.span(),
+				"Enumeration options with fields should not have an explicit specified value",
+			));
+		}
+		let kind = u8::try_from(kind).map_err(|_| {
+			syn::Error::new(variant.ident.span(), "Enumeration has too many options")
+		})?;
+		let is_named_fields = matches!(variant.fields, syn::Fields::Named(_));
+		let solidity_name = cases::camelcase::to_camel_case(&variant.ident.to_string());
+		if solidity_name == "kind" {
+			return Err(syn::Error::new(
+				variant.ident.span(),
+				"Enumeration option name conflicts with \"kind\" field",
+			));
+		}
+		Ok(Self {
+			ident: variant.ident.clone(),
+			docs: extract_docs(&variant.attrs)?,
+			kind,
+			is_named_fields,
+			field_members: variant
+				.fields
+				.iter()
+				.enumerate()
+				.map(|(i, f)| {
+					f.ident
+						.clone()
+						.map_or_else(|| syn::Member::Unnamed(i.into()), syn::Member::Named)
+				})
+				.collect(),
+			field_names: (0..variant.fields.len())
+				.map(|i| format_ident!("field{i}"))
+				.collect(),
+			field_types: variant.fields.iter().map(|f| f.ty.clone()).collect(),
+			solidity_name,
+		})
+	}
+
+	fn has_data(&self) -> bool {
+		!self.field_types.is_empty()
+	}
+
+	/// Type of struct field, holding data of this variant
+	fn data_type(&self) -> TokenStream {
+		let field_types = &self.field_types;
+		if field_types.len() == 1 {
+			quote! {#(#field_types)*}
+		} else {
+			quote! {(#(#field_types,)*)}
+		}
+	}
+
+	/// Variant data, as seen from the struct field
+	fn data_binding(&self) -> TokenStream {
+		let field_names = &self.field_names;
+		if field_names.len() == 1 {
+			quote! {#(#field_names)*}
+		} else {
+			quote! {(#(#field_names,)*)}
+		}
+	}
+
+	fn pattern(&self) -> TokenStream {
+		let ident = &self.ident;
+		let field_members = &self.field_members;
+		let field_names = &self.field_names;
+		if self.is_named_fields {
+			quote! {Self::#ident {#(#field_members: #field_names,)*}}
+		} else if self.has_data() {
+			quote! {Self::#ident (#(#field_names,)*)}
+		} else {
+			quote! {Self::#ident}
+		}
+	}
+}
+
+fn data_types(variants: &[TaggedVariant]) -> impl Iterator<Item = TokenStream> + '_ {
+	variants
+		.iter()
+		.filter(|v| v.has_data())
+		.map(TaggedVariant::data_type)
+}
+
+fn impl_abi_type(
+	name: &syn::Ident,
+	generics: &syn::Generics,
+	tuple_type: &TokenStream,
+	variants: &[TaggedVariant],
+) -> TokenStream {
+	let generics = bounded_generics(
+		generics,
+		data_types(variants),
+		&quote!(::evm_coder::abi::AbiType),
+	);
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	quote! {
+		impl #impl_generics ::evm_coder::abi::AbiType for #name #ty_generics #where_clause {
+			const SIGNATURE: ::evm_coder::custom_signature::SignatureUnit = <#tuple_type as ::evm_coder::abi::AbiType>::SIGNATURE;
+			const IS_DYNAMIC: bool = <#tuple_type as ::evm_coder::abi::AbiType>::IS_DYNAMIC;
+			const HEAD_WORDS: u32 = <#tuple_type as ::evm_coder::abi::AbiType>::HEAD_WORDS;
+		}
+	}
+}
+
+fn impl_abi_write(
+	name: &syn::Ident,
+	generics: &syn::Generics,
+	variants: &[TaggedVariant],
+) -> TokenStream {
+	let generics = bounded_generics(
+		generics,
+		data_types(variants),
+		&quote!(::evm_coder::abi::AbiEncodeZero),
+	);
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	let arms = variants.iter().map(|variant| {
+		let pattern = variant.pattern();
+		let kind = variant.kind;
+		let values = variants.iter().filter(|v| v.has_data()).map(|v| {
+			if v.ident == variant.ident {
+				v.data_binding()
+			} else {
+				let data_type = v.data_type();
+				quote! {::evm_coder::types::Zero::<#data_type>::new()}
+			}
+		});
+		quote! {
+			#pattern => ::evm_coder::abi::AbiEncode::enc(&(#kind, #(#values,)*), writer),
+		}
+	});
+	quote! {
+		impl #impl_generics ::evm_coder::abi::AbiEncode for #name #ty_generics #where_clause {
+			fn enc(&self, writer: &mut ::evm_coder::abi::AbiEncoder) {
+				match self {
+					#(#arms)*
+				}
+			}
+		}
+	}
+}
+
+/// Enums with fields are represented as structs, thus hashed in topics
+fn impl_topic(
+	name: &syn::Ident,
+	generics: &syn::Generics,
+	variants: &[TaggedVariant],
+) -> TokenStream {
+	let generics = optional_bounded_generics(
+		generics,
+		data_types(variants),
+		&quote!(::evm_coder::events::EncodeInPlace + ::core::default::Default),
+	);
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	let arms = variants.iter().map(|variant| {
+		let pattern = variant.pattern();
+		let kind = variant.kind;
+		let values = variants.iter().filter(|v| v.has_data()).map(|v| {
+			if v.ident == variant.ident {
+				v.data_binding()
+			} else {
+				let data_type = v.data_type();
+				quote! {::evm_coder::types::Zero::<#data_type>::new()}
+			}
+		});
+		quote! {
+			#pattern => ::evm_coder::events::EncodeInPlace::encode_in_place(&(#kind, #(#values,)*), out),
+		}
+	});
+	quote! {
+		impl #impl_generics ::evm_coder::events::EncodeInPlace for #name #ty_generics #where_clause {
+			fn encode_in_place(&self, out: &mut ::evm_coder::types::Vec<u8>) {
+				match self {
+					#(#arms)*
+				}
+			}
+		}
+		impl #impl_generics ::evm_coder::events::ToTopic for #name #ty_generics #where_clause {
+			fn to_topic(&self) -> ::evm_coder::types::Topic {
+				::evm_coder::events::reference_topic(self)
+			}
+		}
+	}
+}
+
+fn impl_abi_read(
+	name: &syn::Ident,
+	generics: &syn::Generics,
+	variants: &[TaggedVariant],
+) -> TokenStream {
+	let generics = bounded_generics(
+		generics,
+		data_types(variants),
+		&quote!(::evm_coder::abi::AbiDecodeZero),
+	);
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	let error_str = format!("value not convertible into enum \"{name}\"");
+	let arms = variants.iter().map(|variant| {
+		let kind = variant.kind;
+		let data_types = variants.iter().filter(|v| v.has_data()).map(|v| {
+			let data_type = v.data_type();
+			if v.ident == variant.ident {
+				data_type
+			} else {
+				quote! {::evm_coder::types::Zero<#data_type>}
+			}
+		});
+		let bindings = variants.iter().filter(|v| v.has_data()).map(|v| {
+			if v.ident == variant.ident {
+				v.data_binding()
+			} else {
+				quote! {_}
+			}
+		});
+		let pattern = variant.pattern();
+		quote! {
+			#kind => {
+				let (_, #(#bindings,)*) = <(u8, #(#data_types,)*) as ::evm_coder::abi::AbiDecode>::dec(reader)?;
+				Ok(#pattern)
+			}
+		}
+	});
+	quote! {
+		impl #impl_generics ::evm_coder::abi::AbiDecode for #name #ty_generics #where_clause {
+			fn dec(reader: &mut ::evm_coder::abi::AbiDecoder) -> ::evm_coder::abi::Result<Self> {
+				let kind = <u8 as ::evm_coder::abi::AbiDecode>::dec(&mut reader.clone())?;
+				match kind {
+					#(#arms)*
+					_ => Err(::evm_coder::abi::Error::Custom(#error_str)),
+				}
+			}
+		}
+	}
+}
+
+fn impl_solidity_type(
+	name: &syn::Ident,
+	generics: &syn::Generics,
+	docs: &[String],
+	kind_name: &str,
+	variants: &[TaggedVariant],
+) -> TokenStream {
+	let solidity_name = solidity_struct_name(name, generics);
+	let kind_docs = format!(" Kind of `{name}` value");
+	let generics = bounded_generics(
+		&solidity_generics(generics),
+		data_types(variants),
+		&quote!(::evm_coder::solidity::SolidityTypeName),
+	);
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	let kind_variants = variants.iter().map(|v| {
+		let docs = &v.docs;
+		let name = v.ident.to_string();
+		quote! {
+			SolidityEnumVariant {
+				docs: &[#(#docs),*],
+				name: #name,
+			}
+		}
+	});
+	let solidity_fields = variants.iter().filter(|v| v.has_data()).map(|v| {
+		let data_type = v.data_type();
+		let name = &v.solidity_name;
+		let docs = format!(" Value of `{}` option, zero if `kind` differs", v.ident);
+		quote! {
+			SolidityStructField::<#data_type> {
+				docs: &[#docs],
+				name: #name,
+				ty: ::core::marker::PhantomData,
+			}
+		}
+	});
+	quote! {
+		#[cfg(feature = "stubgen")]
+		impl #impl_generics ::evm_coder::solidity::SolidityStructTy for #name #ty_generics #where_clause {
+			/// Generate solidity definitions for methods described in this struct
+			fn generate_solidity_interface(tc: &evm_coder::solidity::TypeCollector) -> String {
+				use evm_coder::solidity::*;
+				use core::fmt::Write;
+				let kind = SolidityEnum {
+					docs: &[#kind_docs],
+					name: #kind_name,
+					fields: &[#(
+						#kind_variants,
+					)*],
+				};
+				let mut out = String::new();
+				let _ = kind.format(&mut out, tc);
+				tc.collect(out);
+
+				let solidity_name: String = #solidity_name;
+				let interface = SolidityStruct {
+					docs: &[#(#docs),*],
+					name: solidity_name.as_str(),
+					fields: (
+						SolidityStructRawField {
+							docs: &[" Selected option"],
+							name: "kind",
+							ty: #kind_name,
+						},
+						#(
+							#solidity_fields,
+						)*
+					),
+				};
+				let mut out = String::new();
+				let _ = interface.format(&mut out, tc);
+				tc.collect(out);
+				solidity_name
+			}
+		}
+	}
+}
+
+fn impl_solidity_type_name(
+	name: &syn::Ident,
+	generics: &syn::Generics,
+	kind_name: &str,
+	variants: &[TaggedVariant],
+) -> TokenStream {
+	let generics = bounded_generics(
+		&solidity_generics(generics),
+		data_types(variants),
+		&quote!(::evm_coder::solidity::SolidityTypeName),
+	);
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	let default_kind = format!(
+		"{kind_name}.{}",
+		variants.first().expect("enum is not empty").ident
+	);
+	let arg_defaults = variants.iter().filter(|v| v.has_data()).map(|v| {
+		let data_type = v.data_type();
+		quote! {
+			write!(writer, ", ")?;
+			<#data_type as ::evm_coder::solidity::SolidityTypeName>::solidity_default(writer, tc)?;
+		}
+	});
+	quote! {
+		#[cfg(feature = "stubgen")]
+		impl #impl_generics ::evm_coder::solidity::SolidityTypeName for #name #ty_generics #where_clause {
+			fn solidity_name(
+				writer: &mut impl ::core::fmt::Write,
+				tc: &::evm_coder::solidity::TypeCollector,
+			) -> ::core::fmt::Result {
+				write!(writer, "{}", tc.collect_struct::<Self>())
+			}
+
+			fn is_simple() -> bool {
+				false
+			}
+
+			fn solidity_default(
+				writer: &mut impl ::core::fmt::Write,
+				tc: &::evm_coder::solidity::TypeCollector,
+			) -> ::core::fmt::Result {
+				write!(writer, "{}({}", tc.collect_struct::<Self>(), #default_kind)?;
+				#(#arg_defaults)*
+				write!(writer, ")")
+			}
+		}
+	}
+}
+
+pub fn is_tagged_enum(de: &syn::DataEnum) -> bool {
+	de.variants.iter().any(|v| !v.fields.is_empty())
+}
+
+/// Enums with fields are encoded as `(uint8 kind, ...)` tuple, where `kind` is an index
+/// of the selected option, and every option with fields has its own tuple component,
+/// which is zeroed if this option isn't selected
+pub fn expand_tagged_enum(
+	de: &syn::DataEnum,
+	ast: &syn::DeriveInput,
+) -> syn::Result<proc_macro2::TokenStream> {
+	let name = &ast.ident;
+	let generics = &ast.generics;
+	let docs = extract_docs(&ast.attrs)?;
+	check_generics(generics)?;
+	let variants = de
+		.variants
+		.iter()
+		.enumerate()
+		.map(|(i, v)| TaggedVariant::try_from(i, v))
+		.collect::<syn::Result<Vec<_>>>()?;
+	let kind_name = format!("{name}Kind");
+
+	let data_types = data_types(&variants);
+	let tuple_type = quote! {(u8, #(#data_types,)*)};
+
+	let abi_type = impl_abi_type(name, generics, &tuple_type, &variants);
+	let abi_read = impl_abi_read(name, generics, &variants);
+	let abi_write = impl_abi_write(name, generics, &variants);
+	let topic = impl_topic(name, generics, &variants);
+	let solidity_type = impl_solidity_type(name, generics, &docs, &kind_name, &variants);
+	let solidity_type_name = impl_solidity_type_name(name, generics, &kind_name, &variants);
+
+	Ok(quote! {
+		#abi_type
+		#abi_read
+		#abi_write
+		#topic
+		#solidity_type
+		#solidity_type_name
+	})
+}
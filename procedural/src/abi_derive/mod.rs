@@ -1,17 +1,22 @@
+use quote::quote;
+
 mod derive_enum;
 #[cfg(feature = "bondrewd")]
 mod derive_flags;
 mod derive_struct;
+mod derive_tagged_enum;
 
 use derive_enum::expand_enum;
 #[cfg(feature = "bondrewd")]
 use derive_flags::expand_flags;
 use derive_struct::expand_struct;
+use derive_tagged_enum::{expand_tagged_enum, is_tagged_enum};
 
 pub(crate) fn impl_abi_macro(ast: &syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
 	let name = &ast.ident;
 	match &ast.data {
 		syn::Data::Struct(ds) => expand_struct(ds, ast),
+		syn::Data::Enum(de) if is_tagged_enum(de) => expand_tagged_enum(de, ast),
 		syn::Data::Enum(de) => expand_enum(de, ast),
 		syn::Data::Union(_) => Err(syn::Error::new(name.span(), "Unions not supported")),
 	}
@@ -54,3 +59,124 @@ pub fn extract_docs(attrs: &[syn::Attribute]) -> syn::Result<Vec<String>> {
 		})
 		.collect()
 }
+
+/// Generic parameters of derived type, which may be referenced from field types
+fn generic_params(generics: &syn::Generics) -> Vec<&syn::Ident> {
+	generics
+		.params
+		.iter()
+		.filter_map(|p| match p {
+			syn::GenericParam::Type(t) => Some(&t.ident),
+			syn::GenericParam::Const(c) => Some(&c.ident),
+			syn::GenericParam::Lifetime(_) => None,
+		})
+		.collect()
+}
+
+fn mentions_params(tokens: proc_macro2::TokenStream, params: &[&syn::Ident]) -> bool {
+	tokens.into_iter().any(|tt| match tt {
+		proc_macro2::TokenTree::Ident(i) => params.iter().any(|p| **p == i),
+		proc_macro2::TokenTree::Group(g) => mentions_params(g.stream(), params),
+		_ => false,
+	})
+}
+
+pub fn check_generics(generics: &syn::Generics) -> syn::Result<()> {
+	if let Some(lt) = generics.lifetimes().next() {
+		return Err(syn::Error::new(
+			lt.lifetime.span(),
+			"Lifetime parameters not supported",
+		));
+	}
+	Ok(())
+}
+
+/// Adds `bound` to every type from `types`, which depends on generic parameters,
+/// this way only bounds, required by field types, are imposed on parameters
+pub fn bounded_generics(
+	generics: &syn::Generics,
+	types: impl IntoIterator<Item = proc_macro2::TokenStream>,
+	bound: &proc_macro2::TokenStream,
+) -> syn::Generics {
+	let params = generic_params(generics);
+	let mut generics = generics.clone();
+	if params.is_empty() {
+		return generics;
+	}
+	let where_clause = generics.make_where_clause();
+	for ty in types {
+		if mentions_params(ty.clone(), &params) {
+			where_clause
+				.predicates
+				.push(syn::parse_quote! {#ty: #bound});
+		}
+	}
+	generics
+}
+
+/// Adds `bound` to every type from `types`, including ones without generic parameters,
+/// this way impl is only available if every field type satisfies the bound.
+///
+/// Used for optional impls, i.e topic encoding, which shouldn't break derive for fields
+/// implementing only ABI traits. Bounds are wrapped in `for<'__bound>`, because bounds
+/// on concrete types are otherwise rejected by compiler if they can't be satisfied
+pub fn optional_bounded_generics(
+	generics: &syn::Generics,
+	types: impl IntoIterator<Item = proc_macro2::TokenStream>,
+	bound: &proc_macro2::TokenStream,
+) -> syn::Generics {
+	let mut generics = generics.clone();
+	let where_clause = generics.make_where_clause();
+	for ty in types {
+		where_clause
+			.predicates
+			.push(syn::parse_quote! {for<'__bound> #ty: #bound});
+	}
+	generics
+}
+
+/// Names of type parameters are used in monomorphised solidity struct names
+pub fn solidity_generics(generics: &syn::Generics) -> syn::Generics {
+	let mut generics = generics.clone();
+	for param in generics.type_params_mut() {
+		param
+			.bounds
+			.push(syn::parse_quote! {::evm_coder::solidity::SolidityTypeName});
+	}
+	generics
+}
+
+/// Expression, evaluating to solidity name of derived struct.
+/// Generic structs are monomorphised, with names of generic arguments appended to the struct name,
+/// i.e `Page<U256>` => `PageUint256`
+pub fn solidity_struct_name(
+	name: &syn::Ident,
+	generics: &syn::Generics,
+) -> proc_macro2::TokenStream {
+	let name = name.to_string();
+	if generic_params(generics).is_empty() {
+		return quote! {String::from(#name)};
+	}
+	let params = generics.params.iter().filter_map(|p| match p {
+		syn::GenericParam::Type(t) => {
+			let ident = &t.ident;
+			Some(quote! {
+				let mut generic = String::new();
+				let _ = <#ident as ::evm_coder::solidity::SolidityTypeName>::solidity_name(&mut generic, tc);
+				::evm_coder::solidity::push_generic_name(&mut name, &generic);
+			})
+		}
+		syn::GenericParam::Const(c) => {
+			let ident = &c.ident;
+			Some(quote! {
+				::evm_coder::solidity::push_generic_name(&mut name, &#ident.to_string());
+			})
+		}
+		syn::GenericParam::Lifetime(_) => None,
+	});
+	quote! {{
+		let mut name = String::from(#name);
+		#(#params)*
+		name
+	}}
+}
@@ -120,11 +120,6 @@ fn parse_ident_from_path(path: &Path, allow_generics: bool) -> syn::Result<&Iden
 	parse_ident_from_segment(segment, allow_generics)
 }
 
-fn parse_ident_from_type(ty: &Type, allow_generics: bool) -> syn::Result<&Ident> {
-	let path = parse_path(ty)?;
-	parse_ident_from_path(path, allow_generics)
-}
-
 fn pascal_ident_to_call(ident: &Ident) -> Ident {
 	let name = format!("{ident}Call");
 	Ident::new(&name, ident.span())
@@ -164,7 +159,7 @@ pub fn solidity_interface(args: TokenStream, stream: TokenStream) -> TokenStream
 }
 
 /// See documentation for this proc-macro reexported in `evm-coder` crate
-#[proc_macro_derive(ToLog, attributes(indexed))]
+#[proc_macro_derive(ToLog, attributes(indexed, anonymous, event))]
 pub fn to_log(value: TokenStream) -> TokenStream {
 	let input = parse_macro_input!(value as DeriveInput);
 
@@ -176,7 +171,19 @@ pub fn to_log(value: TokenStream) -> TokenStream {
 }
 
 /// See documentation for this proc-macro reexported in `evm-coder` crate
-#[proc_macro_derive(AbiCoder)]
+#[proc_macro_derive(FromLog, attributes(indexed, anonymous, event))]
+pub fn from_log(value: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(value as DeriveInput);
+
+	match to_log::Events::try_from(&input) {
+		Ok(e) => e.expand_from_log(),
+		Err(e) => e.to_compile_error(),
+	}
+	.into()
+}
+
+/// See documentation for this proc-macro reexported in `evm-coder` crate
+#[proc_macro_derive(AbiCoder, attributes(abi))]
 pub fn abi_derive(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 	let ast = parse_macro_input!(input as DeriveInput);
 	let ts = match abi_derive::impl_abi_macro(&ast) {
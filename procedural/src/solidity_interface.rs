@@ -10,9 +10,10 @@ use quote::{format_ident, quote};
 use syn::{
 	parenthesized,
 	parse::{Parse, ParseStream},
+	punctuated::Punctuated,
 	spanned::Spanned,
-	Expr, FnArg, Generics, Ident, ImplItem, ImplItemMethod, Index, ItemImpl, Lit, LitInt, LitStr,
-	Meta, MetaNameValue, PatType, Path, PathArguments, ReturnType, Token, Type,
+	Expr, FnArg, GenericArgument, Generics, Ident, ImplItem, ImplItemMethod, Index, ItemImpl, Lit,
+	LitInt, LitStr, Meta, MetaNameValue, PatType, Path, PathArguments, ReturnType, Token, Type,
 };
 
 use crate::{
@@ -24,6 +25,8 @@ struct Is {
 	name: Ident,
 	pascal_call_name: Ident,
 	via: Option<(Type, Ident)>,
+	/// Read-only accessor for `via`, used for static calls
+	view_via: Option<Ident>,
 	condition: Option<Expr>,
 }
 impl Is {
@@ -35,10 +38,30 @@ impl Is {
 		}
 	}
 
-	fn expand_interface_id(&self) -> proc_macro2::TokenStream {
+	fn expand_client_accessor(&self) -> proc_macro2::TokenStream {
+		let name = &self.name;
+		let pascal_call_name = &self.pascal_call_name;
+		let accessor = format_ident!("{}", cases::snakecase::to_snake_case(&name.to_string()));
+		let docs = format!(
+			" Client for inherited `{name}` interface, sharing transport with this client. Available if `{name}` has client generated"
+		);
+		quote! {
+			#[doc = #docs]
+			pub fn #accessor<'client>(
+				&'client mut self,
+			) -> <#pascal_call_name as ::evm_coder::client::InterfaceClient<&'client mut T>>::Client
+			where
+				#pascal_call_name: ::evm_coder::client::InterfaceClient<&'client mut T>,
+			{
+				<#pascal_call_name as ::evm_coder::client::InterfaceClient<&'client mut T>>::client(&mut self.transport, self.address)
+			}
+		}
+	}
+
+	fn expand_interface_id(&self, generics: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
 		let pascal_call_name = &self.pascal_call_name;
 		quote! {
-			interface_id ^= u32::from_be_bytes(#pascal_call_name::interface_id().0);
+			interface_id ^= u32::from_be_bytes(<#pascal_call_name #generics>::interface_id().0);
 		}
 	}
 
@@ -52,8 +75,14 @@ impl Is {
 				(#condition) &&
 			}
 		});
+		// Inherited interface is implemented by other type, which is accessible by reference
+		// only with read-only accessor
+		let this = self
+			.view_via
+			.as_ref()
+			.map_or_else(|| quote! {this}, |i| quote! {this.#i()});
 		quote! {
-			#condition <#pascal_call_name #generics>::supports_interface(this, interface_id)
+			#condition <#pascal_call_name #generics>::supports_interface(#this, interface_id)
 		}
 	}
 
@@ -79,7 +108,38 @@ impl Is {
 			}
 		});
 		quote! {
-			#call_name::#name(call) #condition => return <#via_typ as ::evm_coder::Callable<#pascal_call_name #generics>>::call(self #via_map, ::evm_coder::types::Msg {
+			#call_name::#name(call) #condition => return <#via_typ as ::evm_coder::Callable<#pascal_call_name #generics>>::call_with_events(self #via_map, ::evm_coder::types::Msg {
+				call,
+				caller: c.caller,
+				value: c.value,
+			}, event_sink)
+		}
+	}
+
+	fn expand_view_variant_call(
+		&self,
+		call_name: &proc_macro2::Ident,
+		generics: &proc_macro2::TokenStream,
+	) -> proc_macro2::TokenStream {
+		let name = &self.name;
+		let pascal_call_name = &self.pascal_call_name;
+		let condition = self.condition.as_ref().map(|condition| {
+			quote! {
+				if ({let this = &self; (#condition)})
+			}
+		});
+		let (via_typ, via_map) = match (&self.via, &self.view_via) {
+			(None, _) => (quote! {Self}, quote! {}),
+			(Some((t, _)), Some(i)) => (quote! {#t}, quote! {.#i()}),
+			// No read-only accessor, parent can't be reached without mutable access
+			(Some(_), None) => {
+				return quote! {
+					#call_name::#name(_) #condition => return Err(<Self as ::evm_coder::Contract>::with_default_post(::evm_coder::STATIC_CALL_ERROR.into()))
+				}
+			}
+		};
+		quote! {
+			#call_name::#name(call) #condition => return <#via_typ as ::evm_coder::CallableView<#pascal_call_name #generics>>::call_view(self #via_map, ::evm_coder::types::Msg {
 				call,
 				caller: c.caller,
 				value: c.value,
@@ -97,6 +157,91 @@ impl Is {
 		}
 	}
 
+	fn expand_selector_count(
+		&self,
+		generics: &proc_macro2::TokenStream,
+	) -> proc_macro2::TokenStream {
+		let pascal_call_name = &self.pascal_call_name;
+		quote! {
+			+ <#pascal_call_name #generics>::SELECTOR_COUNT
+		}
+	}
+
+	fn expand_weight(
+		&self,
+		call_name: &Ident,
+		generics: &proc_macro2::TokenStream,
+		weight: &proc_macro2::TokenStream,
+	) -> proc_macro2::TokenStream {
+		let name = &self.name;
+		let pascal_call_name = &self.pascal_call_name;
+		quote! {
+			#call_name::#name(call) => <#pascal_call_name #generics as ::evm_coder::Weighted<#weight>>::weight(call)
+		}
+	}
+
+	fn expand_weight_bound(
+		&self,
+		generics: &proc_macro2::TokenStream,
+		weight: &proc_macro2::TokenStream,
+	) -> proc_macro2::TokenStream {
+		let pascal_call_name = &self.pascal_call_name;
+		quote! {
+			#pascal_call_name #generics: ::evm_coder::Weighted<#weight>
+		}
+	}
+
+	fn expand_descriptor(&self, generics: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+		let pascal_call_name = &self.pascal_call_name;
+		quote! {
+			&<#pascal_call_name #generics>::DESCRIPTOR
+		}
+	}
+
+	/// Parse `i`-th method of flattened inherited interface
+	fn expand_parse_at(&self, generics: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+		let name = &self.name;
+		let pascal_call_name = &self.pascal_call_name;
+		quote! {
+			if i < <#pascal_call_name #generics>::SELECTOR_COUNT {
+				return Ok(<#pascal_call_name #generics>::parse_at(i, method_id, reader)?.map(Self::#name));
+			}
+			let i = i - <#pascal_call_name #generics>::SELECTOR_COUNT;
+		}
+	}
+
+	/// Lookup of selector in inherited interface, `lookup` is either `selector_at` or
+	/// `selector_name_at`
+	fn expand_selector_lookup(
+		&self,
+		generics: &proc_macro2::TokenStream,
+		lookup: &Ident,
+	) -> proc_macro2::TokenStream {
+		let pascal_call_name = &self.pascal_call_name;
+		quote! {
+			if i < <#pascal_call_name #generics>::SELECTOR_COUNT {
+				return <#pascal_call_name #generics>::#lookup(i);
+			}
+			let i = i - <#pascal_call_name #generics>::SELECTOR_COUNT;
+		}
+	}
+
+	/// Parse fallback/receive call of inherited interface, `parse` is an invocation of
+	/// corresponding [`evm_coder::Call`] method
+	fn expand_parse_special(
+		&self,
+		generics: &proc_macro2::TokenStream,
+		parse: &proc_macro2::TokenStream,
+	) -> proc_macro2::TokenStream {
+		let name = &self.name;
+		let pascal_call_name = &self.pascal_call_name;
+		quote! {
+			if let Some(parsed_call) = <#pascal_call_name #generics>::#parse {
+				return Some(Self::#name(parsed_call))
+			}
+		}
+	}
+
 	fn expand_generator(&self, generics: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
 		let pascal_call_name = &self.pascal_call_name;
 		quote! {
@@ -104,6 +249,28 @@ impl Is {
 		}
 	}
 
+	fn expand_inline_functions(
+		&self,
+		generics: &proc_macro2::TokenStream,
+	) -> proc_macro2::TokenStream {
+		let pascal_call_name = &self.pascal_call_name;
+		quote! {
+			<#pascal_call_name #generics>::solidity_functions()
+		}
+	}
+
+	/// Collect solidity parents of `inline_is` interface into `is` vec
+	fn expand_inline_is(
+		&self,
+		generics: &proc_macro2::TokenStream,
+		is: &proc_macro2::TokenStream,
+	) -> proc_macro2::TokenStream {
+		let pascal_call_name = &self.pascal_call_name;
+		quote! {
+			<#pascal_call_name #generics>::solidity_is(#is);
+		}
+	}
+
 	fn expand_event_generator(&self) -> proc_macro2::TokenStream {
 		let name = &self.name;
 		quote! {
@@ -126,6 +293,7 @@ impl Parse for IsList {
 
 			let mut condition: Option<Expr> = None;
 			let mut via: Option<(Type, Ident)> = None;
+			let mut view_via: Option<Ident> = None;
 
 			if lookahead.peek(syn::token::Paren) {
 				let contents;
@@ -151,6 +319,12 @@ impl Parse for IsList {
 						let method = contents.parse::<Ident>()?;
 						contents.parse::<kw::returns>()?;
 						let ty = contents.parse::<Type>()?;
+						if contents.peek(Token![,]) {
+							contents.parse::<Token![,]>()?;
+							contents.parse::<kw::view>()?;
+							contents.parse::<Token![=]>()?;
+							view_via = Some(contents.parse::<Ident>()?);
+						}
 
 						if via.replace((ty, method)).is_some() {
 							return Err(syn::Error::new(input.span(), "via is already set"));
@@ -174,6 +348,7 @@ impl Parse for IsList {
 				pascal_call_name: pascal_ident_to_call(&name),
 				name,
 				via,
+				view_via,
 				condition,
 			});
 			if input.peek(Token![,]) {
@@ -192,6 +367,8 @@ pub struct InterfaceInfo {
 	inline_is: IsList,
 	events: IsList,
 	expect_selector: Option<u32>,
+	client: Option<Ident>,
+	weight: Option<Type>,
 	enum_attrs: Vec<TokenStream>,
 	enum_variant_attrs: BTreeSet<Ident>,
 }
@@ -202,6 +379,8 @@ impl Parse for InterfaceInfo {
 		let mut inline_is = None;
 		let mut events = None;
 		let mut expect_selector = None;
+		let mut client = None;
+		let mut weight = None;
 		let mut enum_attrs = Vec::new();
 		let mut enum_variant_attrs = BTreeSet::new();
 		// TODO: create proc-macro to optimize proc-macro boilerplate? :D
@@ -244,6 +423,18 @@ impl Parse for InterfaceInfo {
 				{
 					return Err(syn::Error::new(k.span(), "expect_selector is already set"));
 				}
+			} else if lookahead.peek(kw::client) {
+				let k = input.parse::<kw::client>()?;
+				input.parse::<Token![=]>()?;
+				if client.replace(input.parse::<Ident>()?).is_some() {
+					return Err(syn::Error::new(k.span(), "client is already set"));
+				}
+			} else if lookahead.peek(kw::weight) {
+				let k = input.parse::<kw::weight>()?;
+				input.parse::<Token![=]>()?;
+				if weight.replace(input.parse::<Type>()?).is_some() {
+					return Err(syn::Error::new(k.span(), "weight is already set"));
+				}
 			} else if lookahead.peek(Token![enum]) {
 				input.parse::<Token![enum]>()?;
 				let contents;
@@ -271,6 +462,8 @@ impl Parse for InterfaceInfo {
 			inline_is: inline_is.unwrap_or_default(),
 			events: events.unwrap_or_default(),
 			expect_selector,
+			client,
+			weight,
 			enum_attrs,
 			enum_variant_attrs,
 		})
@@ -280,12 +473,16 @@ impl Parse for InterfaceInfo {
 struct MethodInfo {
 	rename_selector: Option<String>,
 	hide: bool,
+	kind: MethodKind,
+	returns: Option<Vec<Option<Ident>>>,
 	enum_attrs: Vec<TokenStream>,
 }
 impl Parse for MethodInfo {
 	fn parse(input: ParseStream) -> syn::Result<Self> {
 		let mut rename_selector = None;
 		let mut hide = false;
+		let mut kind = MethodKind::Normal;
+		let mut returns = None;
 		let mut enum_attrs = Vec::new();
 		while !input.is_empty() {
 			let lookahead = input.lookahead1();
@@ -301,6 +498,37 @@ impl Parse for MethodInfo {
 			} else if lookahead.peek(kw::hide) {
 				input.parse::<kw::hide>()?;
 				hide = true;
+			} else if lookahead.peek(kw::fallback) {
+				let k = input.parse::<kw::fallback>()?;
+				if kind != MethodKind::Normal {
+					return Err(syn::Error::new(k.span(), "function kind is already set"));
+				}
+				kind = MethodKind::Fallback;
+			} else if lookahead.peek(kw::receive) {
+				let k = input.parse::<kw::receive>()?;
+				if kind != MethodKind::Normal {
+					return Err(syn::Error::new(k.span(), "function kind is already set"));
+				}
+				kind = MethodKind::Receive;
+			} else if lookahead.peek(kw::returns) {
+				let k = input.parse::<kw::returns>()?;
+				let contents;
+				parenthesized!(contents in input);
+				// `_` is used for unnamed return values
+				let names = Punctuated::<Option<Ident>, Token![,]>::parse_terminated_with(
+					&contents,
+					|input| {
+						if input.peek(Token![_]) {
+							input.parse::<Token![_]>()?;
+							Ok(None)
+						} else {
+							input.parse().map(Some)
+						}
+					},
+				)?;
+				if returns.replace(names.into_iter().collect()).is_some() {
+					return Err(syn::Error::new(k.span(), "returns is already set"));
+				}
 			} else if lookahead.peek(Token![enum]) {
 				input.parse::<Token![enum]>()?;
 				let contents;
@@ -316,9 +544,23 @@ impl Parse for MethodInfo {
 				return Err(syn::Error::new(input.span(), "expected end"));
 			}
 		}
+		if kind != MethodKind::Normal && rename_selector.is_some() {
+			return Err(syn::Error::new(
+				input.span(),
+				"fallback and receive functions have no selector to rename",
+			));
+		}
+		if kind != MethodKind::Normal && returns.is_some() {
+			return Err(syn::Error::new(
+				input.span(),
+				"fallback and receive functions can't have named returns",
+			));
+		}
 		Ok(Self {
 			rename_selector,
 			hide,
+			kind,
+			returns,
 			enum_attrs,
 		})
 	}
@@ -328,6 +570,7 @@ trait AbiTypeHelper {
 	fn plain(&self) -> syn::Result<&Ident>;
 	fn is_value(&self) -> bool;
 	fn is_caller(&self) -> bool;
+	fn is_event_sink(&self) -> bool;
 	fn is_special(&self) -> bool;
 }
 
@@ -355,8 +598,22 @@ impl AbiTypeHelper for Type {
 		false
 	}
 
+	fn is_event_sink(&self) -> bool {
+		// Sink may be referenced by full path, i.e `&mut evm_coder::types::EventSink`
+		if let Type::Reference(reference) = self {
+			if let Ok(path) = parse_path(&reference.elem) {
+				if let Some(segment) = path.segments.last() {
+					return reference.mutability.is_some()
+						&& segment.arguments.is_empty()
+						&& segment.ident == "EventSink";
+				}
+			}
+		}
+		false
+	}
+
 	fn is_special(&self) -> bool {
-		self.is_caller() || self.is_value()
+		self.is_caller() || self.is_value() || self.is_event_sink()
 	}
 }
 
@@ -410,6 +667,10 @@ impl MethodArg {
 			quote! {
 				c.caller.clone()
 			}
+		} else if self.ty.is_event_sink() {
+			quote! {
+				event_sink
+			}
 		} else {
 			let name = &self.name;
 			quote! {
@@ -418,6 +679,17 @@ impl MethodArg {
 		}
 	}
 
+	fn expand_descriptor(&self) -> proc_macro2::TokenStream {
+		let camel_name = &self.camel_name.to_string();
+		let ty = &self.ty;
+		quote! {
+			::evm_coder::descriptor::ArgDescriptor {
+				name: #camel_name,
+				ty: &<#ty>::SIGNATURE,
+			}
+		}
+	}
+
 	fn expand_solidity_argument(&self) -> proc_macro2::TokenStream {
 		let camel_name = &self.camel_name.to_string();
 		let ty = &self.ty;
@@ -427,6 +699,15 @@ impl MethodArg {
 	}
 }
 
+#[derive(PartialEq, Clone, Copy)]
+enum MethodKind {
+	Normal,
+	/// Called for unknown selectors, `#[solidity(fallback)]`
+	Fallback,
+	/// Called for empty calldata, `#[solidity(receive)]`
+	Receive,
+}
+
 #[derive(PartialEq)]
 enum Mutability {
 	Mutable,
@@ -439,15 +720,20 @@ enum Mutability {
 mod kw {
 	syn::custom_keyword!(via);
 	syn::custom_keyword!(returns);
+	syn::custom_keyword!(view);
 	syn::custom_keyword!(name);
 	syn::custom_keyword!(is);
 	syn::custom_keyword!(inline_is);
 	syn::custom_keyword!(events);
 	syn::custom_keyword!(expect_selector);
+	syn::custom_keyword!(client);
+	syn::custom_keyword!(weight);
 	syn::custom_keyword!(enum_attr);
 
 	syn::custom_keyword!(rename_selector);
 	syn::custom_keyword!(hide);
+	syn::custom_keyword!(fallback);
+	syn::custom_keyword!(receive);
 }
 
 /// Rust methods are parsed into this structure when Solidity code is generated
@@ -457,25 +743,32 @@ struct Method {
 	pascal_name: Ident,
 	screaming_name: Ident,
 	hide: bool,
+	kind: MethodKind,
 	args: Vec<MethodArg>,
 	has_normal_args: bool,
 	has_value_args: bool,
 	mutability: Mutability,
 	result: Box<Type>,
+	/// Result tuple is returned as multiple values, with optional solidity names
+	returns: Option<Vec<(Option<String>, Type)>>,
 	docs: Vec<String>,
 	enum_attrs: Vec<TokenStream>,
+	weight: Option<Expr>,
 }
 impl Method {
 	fn try_from(value: &mut ImplItemMethod, variant_attrs: &BTreeSet<Ident>) -> syn::Result<Self> {
 		let mut info = MethodInfo {
 			rename_selector: None,
 			hide: false,
+			kind: MethodKind::Normal,
+			returns: None,
 			enum_attrs: Vec::new(),
 		};
 		let mut docs = Vec::new();
 
 		let mut to_remove = Vec::new();
 		let mut extra_enum_attrs = Vec::new();
+		let mut weight = None;
 		for (i, attr) in value.attrs.iter().enumerate() {
 			let ident = parse_ident_from_path(&attr.path, false)?;
 			if ident == "solidity" {
@@ -495,6 +788,11 @@ impl Method {
 				let tokens = &attr.tokens;
 				extra_enum_attrs.push(quote! {#path #tokens});
 				to_remove.push(i);
+			} else if ident == "weight" {
+				if weight.replace(attr.parse_args::<Expr>()?).is_some() {
+					return Err(syn::Error::new(attr.span(), "weight is already set"));
+				}
+				to_remove.push(i);
 			}
 		}
 		for i in to_remove.iter().rev() {
@@ -547,12 +845,99 @@ impl Method {
 				"payable function should be mutable",
 			));
 		}
+		if mutability != Mutability::Mutable && args.iter().any(|arg| arg.ty.is_event_sink()) {
+			return Err(syn::Error::new(
+				args.iter()
+					.find(|arg| arg.ty.is_event_sink())
+					.unwrap()
+					.ty
+					.span(),
+				"function emitting events should be mutable",
+			));
+		}
+		match info.kind {
+			MethodKind::Normal => {}
+			MethodKind::Receive => {
+				if !args.iter().any(MethodArg::is_value) {
+					return Err(syn::Error::new(
+						ident.span(),
+						"receive function should be payable",
+					));
+				}
+				if let Some(arg) = args.iter().find(|arg| !arg.is_special()) {
+					return Err(syn::Error::new(
+						arg.ty.span(),
+						"receive function can't have arguments",
+					));
+				}
+			}
+			MethodKind::Fallback => {
+				if mutability != Mutability::Mutable {
+					return Err(syn::Error::new(
+						ident.span(),
+						"fallback function should be mutable",
+					));
+				}
+				for (i, arg) in args.iter().filter(|arg| !arg.is_special()).enumerate() {
+					let is_bytes = matches!(arg.ty.plain(), Ok(ident) if ident == "Bytes");
+					if i != 0 || !is_bytes {
+						return Err(syn::Error::new(
+							arg.ty.span(),
+							"fallback function may only take `Bytes` input",
+						));
+					}
+				}
+			}
+		}
 
 		let result = match &value.sig.output {
 			ReturnType::Type(_, ty) => ty,
 			ReturnType::Default => return Err(syn::Error::new(value.sig.output.span(), "interface method should return Result<value>\nif there is no value to return - specify void (which is alias to unit)")),
 		};
 
+		if info.kind == MethodKind::Fallback {
+			let has_input = args.iter().any(|arg| !arg.is_special());
+			let returns_bytes = matches!(
+				parse_result_value(result).map(AbiTypeHelper::plain),
+				Some(Ok(ident)) if ident == "Bytes"
+			);
+			if has_input && !returns_bytes {
+				return Err(syn::Error::new(
+					result.span(),
+					"fallback function with input should return `Bytes`",
+				));
+			}
+			if !has_input && returns_bytes {
+				return Err(syn::Error::new(
+					result.span(),
+					"fallback function without input can't return output",
+				));
+			}
+		}
+
+		let returns = match info.returns {
+			Some(names) => {
+				let types = parse_result_tuple(result).ok_or_else(|| {
+					syn::Error::new(
+						result.span(),
+						"named returns require method to return tuple, i.e `Result<(A, B)>`",
+					)
+				})?;
+				if names.len() != types.len() {
+					return Err(syn::Error::new(
+						result.span(),
+						format!("expected {} return names, got {}", types.len(), names.len()),
+					));
+				}
+				let names = names.iter().map(|n| {
+					n.as_ref()
+						.map(|n| cases::camelcase::to_camel_case(&n.to_string()))
+				});
+				Some(names.zip(types).collect())
+			}
+			None => None,
+		};
+
 		let camel_name = info
 			.rename_selector
 			.unwrap_or_else(|| cases::camelcase::to_camel_case(&ident.to_string()));
@@ -565,13 +950,16 @@ impl Method {
 			pascal_name: snake_ident_to_pascal(ident),
 			screaming_name: snake_ident_to_screaming(ident),
 			hide: info.hide,
+			kind: info.kind,
 			args,
 			has_normal_args,
 			has_value_args,
 			mutability,
 			result: result.clone(),
+			returns,
 			docs,
 			enum_attrs: [info.enum_attrs, extra_enum_attrs].concat(),
+			weight,
 		})
 	}
 	fn expand_call_def(&self) -> proc_macro2::TokenStream {
@@ -624,6 +1012,108 @@ impl Method {
 		}
 	}
 
+	/// Weight expression, evaluated over call arguments, default weight is used if not specified
+	fn expand_weight(&self, call_name: &Ident) -> proc_macro2::TokenStream {
+		let pascal_name = &self.pascal_name;
+		let weight = match &self.weight {
+			Some(weight) => weight,
+			None => {
+				return quote! {
+					#call_name::#pascal_name {..} => ::core::default::Default::default()
+				}
+			}
+		};
+		let names = self
+			.args
+			.iter()
+			.filter(|a| !a.is_special())
+			.map(|a| &a.name);
+		quote! {
+			#call_name::#pascal_name {#(#names,)* ..} => (#weight)
+		}
+	}
+
+	fn expand_descriptor(&self) -> proc_macro2::TokenStream {
+		let name = self.name.to_string();
+		let camel_name = &self.camel_name;
+		let screaming_name = &self.screaming_name;
+		let screaming_name_signature = format_ident!("{}_SIGNATURE", &self.screaming_name);
+		let args = self
+			.args
+			.iter()
+			.filter(|a| !a.is_special())
+			.map(MethodArg::expand_descriptor);
+		let mutability = match self.mutability {
+			Mutability::Mutable => quote! {Mutable},
+			Mutability::View => quote! {View},
+			Mutability::Pure => quote! {Pure},
+		};
+		let returns = match &self.returns {
+			Some(returns) => returns
+				.iter()
+				.map(|(name, ty)| {
+					let name = name.as_deref().unwrap_or("");
+					quote! {
+						::evm_coder::descriptor::ArgDescriptor {
+							name: #name,
+							ty: &<#ty as ::evm_coder::abi::AbiType>::SIGNATURE,
+						}
+					}
+				})
+				.collect(),
+			None => match parse_result_value(&self.result) {
+				Some(Type::Tuple(tuple)) if tuple.elems.is_empty() => Vec::new(),
+				_ => {
+					let result = &self.result;
+					Vec::from([quote! {
+						::evm_coder::descriptor::ArgDescriptor {
+							name: "",
+							ty: &<<#result as ::evm_coder::client::MethodResult>::Value as ::evm_coder::abi::AbiType>::SIGNATURE,
+						}
+					}])
+				}
+			},
+		};
+		let is_payable = self.has_value_args;
+		let hide = self.hide;
+		let docs = &self.docs;
+		quote! {
+			::evm_coder::descriptor::MethodDescriptor {
+				name: #name,
+				solidity_name: #camel_name,
+				selector: Self::#screaming_name,
+				signature: &Self::#screaming_name_signature,
+				args: &[#(#args,)*],
+				returns: &[#(#returns,)*],
+				mutability: ::evm_coder::descriptor::Mutability::#mutability,
+				is_payable: #is_payable,
+				hide: #hide,
+				docs: &[#(#docs,)*],
+			}
+		}
+	}
+
+	fn expand_special_descriptor(&self) -> proc_macro2::TokenStream {
+		let name = self.name.to_string();
+		let is_payable = self.has_value_args;
+		let docs = &self.docs;
+		quote! {
+			::evm_coder::descriptor::SpecialMethodDescriptor {
+				name: #name,
+				is_payable: #is_payable,
+				docs: &[#(#docs,)*],
+			}
+		}
+	}
+
+	fn expand_selector_name(&self, solidity_name: &str) -> proc_macro2::TokenStream {
+		let screaming_name_signature = format_ident!("{}_SIGNATURE", &self.screaming_name);
+		let prefix = format!("{solidity_name}::{} (", self.name);
+		quote! {
+			::evm_coder::make_signature!(new fixed(#prefix) nameof(Self::#screaming_name_signature) fixed(")"))
+		}
+	}
+
 	fn expand_interface_id(&self) -> proc_macro2::TokenStream {
 		let screaming_name = &self.screaming_name;
 		quote! {
@@ -631,9 +1121,9 @@ impl Method {
 		}
 	}
 
-	fn expand_parse(&self) -> proc_macro2::TokenStream {
+	/// Match arm, which parses this method, `pattern` is either selector or method index
+	fn expand_parse(&self, pattern: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
 		let pascal_name = &self.pascal_name;
-		let screaming_name = &self.screaming_name;
 		if self.has_normal_args {
 			let args_iter = self.args.iter().filter(|a| !a.is_special());
 			let arg_type = args_iter.clone().map(|a| &a.ty);
@@ -641,7 +1131,7 @@ impl Method {
 				.enumerate()
 				.map(|(i, m)| m.expand_extract_parsed(i));
 			quote! {
-				Self::#screaming_name => {
+				#pattern => {
 					let parsed = <(#(#arg_type,)*) as ::evm_coder::abi::AbiDecode>::abi_decode(reader)?;
 					return Ok(Some(Self::#pascal_name {
 						#(
@@ -651,7 +1141,7 @@ impl Method {
 				}
 			}
 		} else {
-			quote! { Self::#screaming_name => return Ok(Some(Self::#pascal_name)) }
+			quote! { #pattern => return Ok(Some(Self::#pascal_name)) }
 		}
 	}
 
@@ -684,9 +1174,27 @@ impl Method {
 			Mutability::Pure => quote! {Self::},
 		};
 		let args = self.args.iter().map(MethodArg::expand_call_arg);
+		// Fallback output is returned as is, without ABI encoding
+		let encode = if self.kind == MethodKind::Fallback {
+			quote! {::evm_coder::FallbackOutput::into_output(res)}
+		} else if self.returns.is_some() {
+			// Multiple values are encoded same way as arguments
+			quote! {res.abi_encode()}
+		} else {
+			quote! {(res,).abi_encode()}
+		};
+
+		let value_check = (!self.has_value_args).then(|| {
+			quote! {
+				if <Self as ::evm_coder::Contract>::REJECT_NON_PAYABLE_VALUE && !c.value.is_zero() {
+					return Err(<Self as ::evm_coder::Contract>::with_default_post(::evm_coder::NON_PAYABLE_ERROR.into()));
+				}
+			}
+		});
 
 		quote! {
 			#call_name::#pascal_name #matcher => {
+				#value_check
 				#[allow(deprecated)]
 				let result = #receiver #name(
 					#(
@@ -696,13 +1204,29 @@ impl Method {
 				let result = #result_macro_name!(result);
 				result.map(|post| {
 					<Self as ::evm_coder::Contract>::map_post(post, |res| {
-						(res,).abi_encode()
+						#encode
 					})
 				})
 			}
 		}
 	}
 
+	/// Same as [`Self::expand_variant_call`], but mutable methods are rejected, as they can't be
+	/// called in static context
+	fn expand_view_variant_call(
+		&self,
+		result_macro_name: &Path,
+		call_name: &proc_macro2::Ident,
+	) -> proc_macro2::TokenStream {
+		if self.mutability != Mutability::Mutable {
+			return self.expand_variant_call(result_macro_name, call_name);
+		}
+		let pascal_name = &self.pascal_name;
+		quote! {
+			#call_name::#pascal_name {..} => Err(<Self as ::evm_coder::Contract>::with_default_post(::evm_coder::STATIC_CALL_ERROR.into()))
+		}
+	}
+
 	fn expand_custom_signature(&self) -> proc_macro2::TokenStream {
 		let mut args = TokenStream::new();
 
@@ -723,6 +1247,104 @@ impl Method {
 		quote! { ::evm_coder::make_signature!(new fixed(#func_name) fixed("(") #args fixed(")")) }
 	}
 
+	fn expand_client_method(&self, call_name: &Ident) -> proc_macro2::TokenStream {
+		let name = &self.name;
+		let screaming_name = &self.screaming_name;
+		let result = &self.result;
+		let docs = &self.docs;
+		let params = self
+			.args
+			.iter()
+			.filter(|a| !a.is_caller() && !a.ty.is_event_sink())
+			.map(|a| {
+				let name = &a.name;
+				let ty = &a.ty;
+				quote! {#name: #ty}
+			});
+		let args = self
+			.args
+			.iter()
+			.filter(|a| !a.is_special())
+			.map(|a| &a.name);
+		let value = self.args.iter().find(|a| a.is_value()).map_or_else(
+			|| quote! {::evm_coder::types::Value::zero()},
+			|a| {
+				let name = &a.name;
+				quote! {#name}
+			},
+		);
+
+		let decode = if self.returns.is_some() {
+			quote! {
+				<<#result as ::evm_coder::client::MethodResult>::Value>::abi_decode(&output)
+			}
+		} else {
+			quote! {
+				<(<#result as ::evm_coder::client::MethodResult>::Value,)>::abi_decode(&output)
+					.map(|(result,)| result)
+			}
+		};
+
+		quote! {
+			#(#[doc = #docs])*
+			pub fn #name(
+				&mut self,
+				#(#params,)*
+			) -> ::core::result::Result<
+				<#result as ::evm_coder::client::MethodResult>::Value,
+				::evm_coder::client::ClientError<T::Error>,
+			> {
+				use ::evm_coder::abi::{AbiDecode, AbiEncode};
+				let (encoded, value) = ((#(#args,)*).abi_encode(), #value);
+				let mut data = ::evm_coder::types::Vec::from(#call_name::#screaming_name.0);
+				data.extend_from_slice(&encoded);
+				let output = self
+					.transport
+					.call(self.address, data, value)
+					.map_err(::evm_coder::client::ClientError::Transport)?;
+				#decode
+					.map_err(::evm_coder::client::ClientError::Decode)
+			}
+		}
+	}
+
+	/// Construct call of fallback function from `input`
+	fn expand_parse_fallback(&self) -> proc_macro2::TokenStream {
+		let pascal_name = &self.pascal_name;
+		if let Some(arg) = self.args.iter().find(|a| !a.is_special()) {
+			let name = &arg.name;
+			quote! {
+				Some(Self::#pascal_name {
+					#name: ::evm_coder::types::Bytes(input.into()),
+				})
+			}
+		} else {
+			quote! {Some(Self::#pascal_name)}
+		}
+	}
+
+	/// Doccomment of method with `@return` NatSpec appended for named returns, which don't have it
+	fn solidity_docs(&self) -> Vec<String> {
+		let mut docs = self.docs.clone();
+		let names = self
+			.returns
+			.iter()
+			.flatten()
+			.filter_map(|(name, _)| name.as_ref());
+		for name in names {
+			let documented = self.docs.iter().any(|line| {
+				line.trim()
+					.strip_prefix("@return")
+					.and_then(|tag| tag.split_whitespace().next())
+					== Some(name)
+			});
+			if !documented {
+				docs.push(format!(" @return {name}"));
+			}
+		}
+		docs
+	}
+
 	fn expand_solidity_function(&self) -> proc_macro2::TokenStream {
 		let camel_name = &self.camel_name;
 		let mutability = match self.mutability {
@@ -731,17 +1353,48 @@ impl Method {
 			Mutability::Pure => quote! {SolidityMutability::Pure},
 		};
 		let result = &self.result;
+		let docs = &self.docs;
+		let is_payable = self.has_value_args;
+		match self.kind {
+			MethodKind::Normal => {}
+			MethodKind::Fallback => {
+				let has_input = self.has_normal_args;
+				return quote! {
+					SolidityFallback {
+						docs: &[#(#docs),*],
+						is_payable: #is_payable,
+						has_input: #has_input,
+					}
+				};
+			}
+			MethodKind::Receive => {
+				return quote! {
+					SolidityReceive {
+						docs: &[#(#docs),*],
+					}
+				};
+			}
+		}
 
 		let args = self
 			.args
 			.iter()
 			.filter(|a| !a.is_special())
 			.map(MethodArg::expand_solidity_argument);
-		let docs = &self.docs;
 		let screaming_name = &self.screaming_name;
 		let hide = self.hide;
 		let custom_signature = self.expand_custom_signature();
-		let is_payable = self.has_value_args;
+		let docs = self.solidity_docs();
+		let solidity_result = match &self.returns {
+			Some(returns) => {
+				let returns = returns.iter().map(|(name, ty)| match name {
+					Some(name) => quote! {<NamedArgument<#ty>>::new(#name)},
+					None => quote! {<UnnamedArgument<#ty>>::default()},
+				});
+				quote! {(#(#returns,)*)}
+			}
+			None => quote! {<UnnamedArgument<#result>>::default()},
+		};
 
 		quote! {
 			SolidityFunction {
@@ -757,12 +1410,36 @@ impl Method {
 						#args,
 					)*
 				),
-				result: <UnnamedArgument<#result>>::default(),
+				result: #solidity_result,
 			}
 		}
 	}
 }
 
+/// Value type of `Result<T>`
+fn parse_result_value(result: &Type) -> Option<&Type> {
+	let segment = match result {
+		Type::Path(path) => path.path.segments.last()?,
+		_ => return None,
+	};
+	let args = match &segment.arguments {
+		PathArguments::AngleBracketed(args) => args,
+		_ => return None,
+	};
+	match args.args.first()? {
+		GenericArgument::Type(ty) => Some(ty),
+		_ => None,
+	}
+}
+
+/// Types of tuple, returned by method as `Result<(A, B)>`
+fn parse_result_tuple(result: &Type) -> Option<Vec<Type>> {
+	match parse_result_value(result)? {
+		Type::Tuple(tuple) => Some(tuple.elems.iter().cloned().collect()),
+		_ => None,
+	}
+}
+
 fn generics_list(gen: &Generics) -> proc_macro2::TokenStream {
 	if gen.params.is_empty() {
 		return quote! {};
@@ -820,7 +1497,14 @@ impl SolidityInterface {
 
 		for item in &mut value.items {
 			if let ImplItem::Method(method) = item {
-				methods.push(Method::try_from(method, &info.enum_variant_attrs)?);
+				let method = Method::try_from(method, &info.enum_variant_attrs)?;
+				if let (Some(weight), None) = (&method.weight, &info.weight) {
+					return Err(syn::Error::new(
+						weight.span(),
+						"weight type should be specified with `weight = Type` interface option",
+					));
+				}
+				methods.push(method);
 			}
 		}
 		let mut docs = vec![];
@@ -860,6 +1544,54 @@ impl SolidityInterface {
 		let solidity_name = self.info.name.to_string();
 		let call_name = pascal_ident_to_call(&self.info.name);
 		let generics = self.generics;
+		let client = match &self.info.client {
+			Some(client) if !generics.params.is_empty() => {
+				return syn::Error::new(
+					client.span(),
+					"client generation is not supported for generic interfaces",
+				)
+				.to_compile_error()
+			}
+			Some(client) => {
+				let docs = format!(" Typed client for `{solidity_name}` interface");
+				let methods = self
+					.methods
+					.iter()
+					.filter(|m| m.kind == MethodKind::Normal)
+					.map(|m| m.expand_client_method(&call_name));
+				let accessors = self
+					.info
+					.inline_is
+					.0
+					.iter()
+					.chain(self.info.is.0.iter())
+					.map(Is::expand_client_accessor);
+				Some(quote! {
+					#[doc = #docs]
+					pub struct #client<T> {
+						/// Transport, used for calls
+						pub transport: T,
+						/// Address of called contract
+						pub address: ::evm_coder::types::Address,
+					}
+					impl<T: ::evm_coder::client::Transport> #client<T> {
+						/// Create client for contract with specified address
+						pub fn new(transport: T, address: ::evm_coder::types::Address) -> Self {
+							Self { transport, address }
+						}
+						#(#methods)*
+						#(#accessors)*
+					}
+					impl<T: ::evm_coder::client::Transport> ::evm_coder::client::InterfaceClient<T> for #call_name {
+						type Client = #client<T>;
+						fn client(transport: T, address: ::evm_coder::types::Address) -> Self::Client {
+							#client::new(transport, address)
+						}
+					}
+				})
+			}
+			None => None,
+		};
 		let gen_ref = generics_reference(&generics);
 		let gen_data = generics_data(&generics);
 		let gen_stub = generics_stub(&generics);
@@ -886,8 +1618,20 @@ impl SolidityInterface {
 			.iter()
 			.chain(self.info.is.0.iter())
 			.map(|c| Is::expand_variant_call(c, &call_name, &gen_ref));
+		let call_view_variants = self
+			.info
+			.inline_is
+			.0
+			.iter()
+			.chain(self.info.is.0.iter())
+			.map(|c| Is::expand_view_variant_call(c, &call_name, &gen_ref));
 
-		let inline_interface_id = self.info.inline_is.0.iter().map(Is::expand_interface_id);
+		let inline_interface_id = self
+			.info
+			.inline_is
+			.0
+			.iter()
+			.map(|is| is.expand_interface_id(&gen_ref));
 		let supports_interface = self
 			.info
 			.is
@@ -895,25 +1639,154 @@ impl SolidityInterface {
 			.iter()
 			.map(|is| Is::expand_supports_interface(is, &gen_ref));
 
+		// Fallback and receive functions have no selectors
+		let selector_methods = self.methods.iter().filter(|m| m.kind == MethodKind::Normal);
 		let calls = self.methods.iter().map(Method::expand_call_def);
-		let consts = self.methods.iter().map(Method::expand_const);
-		let interface_id = self.methods.iter().map(Method::expand_interface_id);
-		let parsers = self.methods.iter().map(Method::expand_parse);
+		let consts = selector_methods.clone().map(Method::expand_const);
+		let interface_id = selector_methods.clone().map(Method::expand_interface_id);
+		let parsers = selector_methods.clone().map(|m| {
+			let screaming_name = &m.screaming_name;
+			m.expand_parse(&quote! {Self::#screaming_name})
+		});
+		let index_parsers = selector_methods
+			.clone()
+			.enumerate()
+			.map(|(i, m)| m.expand_parse(&quote! {#i}));
+		let method_descriptors = selector_methods.clone().map(Method::expand_descriptor);
+		let special_descriptor = |kind| match self.methods.iter().find(|m| m.kind == kind) {
+			Some(method) => {
+				let descriptor = method.expand_special_descriptor();
+				quote! {Some(#descriptor)}
+			}
+			None => quote! {None},
+		};
+		let fallback_descriptor = special_descriptor(MethodKind::Fallback);
+		let receive_descriptor = special_descriptor(MethodKind::Receive);
+		// Flattened list of selectors of this interface and all of its parents, used for collision check
+		let own_selector_count = selector_methods.clone().count();
+		let own_selectors = selector_methods.clone().enumerate().map(|(i, m)| {
+			let screaming_name = &m.screaming_name;
+			quote! {#i => return Self::#screaming_name}
+		});
+		let own_selector_names = selector_methods.enumerate().map(|(i, m)| {
+			let name = m.expand_selector_name(&solidity_name);
+			quote! {#i => return #name}
+		});
+		let parents = || self.info.inline_is.0.iter().chain(self.info.is.0.iter());
+		let parent_descriptors = self
+			.info
+			.is
+			.0
+			.iter()
+			.map(|is| is.expand_descriptor(&gen_ref));
+		let inline_parent_descriptors = self
+			.info
+			.inline_is
+			.0
+			.iter()
+			.map(|is| is.expand_descriptor(&gen_ref));
+		let parent_parse_at = parents().map(|is| is.expand_parse_at(&gen_ref));
+		// Array length can't depend on generic parameters, generic interfaces are parsed by
+		// asking every parent in turn
+		let parse_lookup = if generics.params.is_empty() {
+			quote! {
+				const SELECTOR_TABLE: [(u32, usize); <#call_name>::SELECTOR_COUNT] = {
+					let mut table = [(0, 0); <#call_name>::SELECTOR_COUNT];
+					let mut i = 0;
+					while i < table.len() {
+						table[i] = (u32::from_be_bytes(<#call_name>::selector_at(i).0), i);
+						i += 1;
+					}
+					::evm_coder::sort_selector_table(table)
+				};
+				match ::evm_coder::find_selector(&SELECTOR_TABLE, u32::from_be_bytes(method_id.0)) {
+					Some(i) => Self::parse_at(i, method_id, reader),
+					None => Ok(None),
+				}
+			}
+		} else {
+			quote! {
+				if let Some(parsed_call) = Self::parse_own(method_id, reader)? {
+					return Ok(Some(parsed_call));
+				}
+				#(
+					#call_parse
+				)else*
+				Ok(None)
+			}
+		};
+		let parent_selector_counts = parents().map(|is| is.expand_selector_count(&gen_ref));
+		let parent_selectors =
+			parents().map(|is| is.expand_selector_lookup(&gen_ref, &format_ident!("selector_at")));
+		let parent_selector_names = parents()
+			.map(|is| is.expand_selector_lookup(&gen_ref, &format_ident!("selector_name_at")));
+		// Generic interface can't be checked without instantiation, it is checked on `parse` usage instead
+		let selector_collision_check = generics.params.is_empty().then(|| {
+			quote! {
+				const _: () = <#call_name>::SELECTOR_COLLISION_CHECK;
+			}
+		});
+		// Own fallback/receive function is preferred over inherited ones
+		let special_parser = |kind: MethodKind, parse: proc_macro2::TokenStream| {
+			if let Some(method) = self.methods.iter().find(|m| m.kind == kind) {
+				return method.expand_parse_fallback();
+			}
+			let inherited = self
+				.info
+				.inline_is
+				.0
+				.iter()
+				.chain(self.info.is.0.iter())
+				.map(|is| Is::expand_parse_special(is, &gen_ref, &parse));
+			quote! {
+				#(#inherited)*
+				None
+			}
+		};
+		let parse_fallback = special_parser(MethodKind::Fallback, quote! {parse_fallback(input)});
+		let parse_receive = special_parser(MethodKind::Receive, quote! {parse_receive()});
 		let call_variants_this = self
 			.methods
 			.iter()
 			.map(|m| Method::expand_variant_call(m, &self.result_macro_name, &call_name));
+		let call_view_variants_this = self
+			.methods
+			.iter()
+			.map(|m| Method::expand_view_variant_call(m, &self.result_macro_name, &call_name));
 		let solidity_functions = self.methods.iter().map(Method::expand_solidity_function);
 
-		// TODO: Inline inline_is
+		let solidity_inline_functions = self
+			.info
+			.inline_is
+			.0
+			.iter()
+			.map(|is| Is::expand_inline_functions(is, &gen_ref));
 		let solidity_is = self
 			.info
 			.is
 			.0
 			.iter()
-			.chain(self.info.inline_is.0.iter())
-			.map(|is| is.name.to_string());
-		let solidity_events_is = self.info.events.0.iter().map(|is| is.name.to_string());
+			.map(|is| is.name.to_string())
+			.collect::<Vec<_>>();
+		let solidity_inline_parents_is = self
+			.info
+			.inline_is
+			.0
+			.iter()
+			.map(|is| Is::expand_inline_is(is, &gen_ref, &quote! {is}));
+		let solidity_inline_is = self
+			.info
+			.inline_is
+			.0
+			.iter()
+			.map(|is| Is::expand_inline_is(is, &gen_ref, &quote! {&mut inline_is}));
+		let solidity_events_is = self
+			.info
+			.events
+			.0
+			.iter()
+			.map(|is| is.name.to_string())
+			.collect::<Vec<_>>();
 		let solidity_generators = self
 			.info
 			.is
@@ -932,10 +1805,70 @@ impl SolidityInterface {
             }
         });
 
+		let weights = self.methods.iter().map(|m| m.expand_weight(&call_name));
+		let weighted = if let Some(weight) = &self.info.weight {
+			let weight = quote! {#weight};
+			let parent_weights =
+				parents().map(|is| is.expand_weight(&call_name, &gen_ref, &weight));
+			quote! {
+				impl #gen_ref ::evm_coder::Weighted<#weight> for #call_name #gen_ref {
+					#[allow(unused_variables)]
+					fn weight(&self) -> #weight {
+						match self {
+							#call_name::ERC165Call(..) => ::core::default::Default::default(),
+							#(#weights,)*
+							#(#parent_weights,)*
+						}
+					}
+				}
+				impl #gen_ref #call_name #gen_ref {
+					/// Weight of this call, calculated from its arguments
+					pub fn weight(&self) -> #weight {
+						<Self as ::evm_coder::Weighted<#weight>>::weight(self)
+					}
+				}
+			}
+		} else {
+			// Interface has no weights of its own, but its parents may have
+			let weight = quote! {CallWeight};
+			let parent_weights =
+				parents().map(|is| is.expand_weight(&call_name, &gen_ref, &weight));
+			let parent_bounds = parents().map(|is| is.expand_weight_bound(&gen_ref, &weight));
+			let gen_list = generics_list(&generics);
+			let params = if generics.params.is_empty() {
+				quote! {#weight: ::core::default::Default}
+			} else {
+				quote! {#gen_list, #weight: ::core::default::Default}
+			};
+			quote! {
+				impl<#params> ::evm_coder::Weighted<#weight> for #call_name #gen_ref
+				where #(#parent_bounds,)*
+				{
+					fn weight(&self) -> #weight {
+						match self {
+							#(#parent_weights,)*
+							_ => ::core::default::Default::default(),
+						}
+					}
+				}
+			}
+		};
+
+		let supports_interface_call = quote! {
+			#call_name::ERC165Call(::evm_coder::ERC165Call::SupportsInterface {interface_id}, _) => {
+				if <Self as ::evm_coder::Contract>::REJECT_NON_PAYABLE_VALUE && !c.value.is_zero() {
+					return Err(<Self as ::evm_coder::Contract>::with_default_post(::evm_coder::NON_PAYABLE_ERROR.into()));
+				}
+				let data = <#call_name #gen_ref>::supports_interface(self, interface_id).abi_encode();
+				return Ok(<Self as ::evm_coder::Contract>::with_default_post(data));
+			}
+		};
+
 		quote! {
 			#(
 				const _: ::core::marker::PhantomData<#solidity_events_idents> = ::core::marker::PhantomData;
 			)*
+			#client
 			#[derive(Debug)]
 			#(#[doc = #docs])*
 			#(#[#enum_attrs])*
@@ -951,11 +1884,102 @@ impl SolidityInterface {
 			}
 
 			#expect_selector
+			#selector_collision_check
+			#weighted
 
 			impl #gen_ref #call_name #gen_ref {
 				#(
 					#consts
 				)*
+				/// Static description of this interface
+				pub const DESCRIPTOR: ::evm_coder::descriptor::InterfaceDescriptor = ::evm_coder::descriptor::InterfaceDescriptor {
+					name: #solidity_name,
+					interface_id: Self::interface_id(),
+					docs: &[#(#docs,)*],
+					methods: &[#(#method_descriptors,)*],
+					fallback: #fallback_descriptor,
+					receive: #receive_descriptor,
+					parents: &[#(#parent_descriptors,)*],
+					inline_parents: &[#(#inline_parent_descriptors,)*],
+				};
+				#[doc(hidden)]
+				pub const SELECTOR_COUNT: usize = #own_selector_count #(#parent_selector_counts)*;
+				/// Selector of `i`-th method in this interface and its parents
+				#[doc(hidden)]
+				#[allow(unused_variables)]
+				pub const fn selector_at(i: usize) -> ::evm_coder::types::Bytes4 {
+					match i {
+						#(#own_selectors,)*
+						_ => {},
+					}
+					let i = i - #own_selector_count;
+					#(#parent_selectors)*
+					panic!("selector index out of bounds")
+				}
+				/// Name of `i`-th method in this interface and its parents, in `Interface::method (signature)` format
+				#[doc(hidden)]
+				#[allow(unused_variables)]
+				pub const fn selector_name_at(i: usize) -> ::evm_coder::custom_signature::SignatureUnit {
+					match i {
+						#(#own_selector_names,)*
+						_ => {},
+					}
+					let i = i - #own_selector_count;
+					#(#parent_selector_names)*
+					panic!("selector index out of bounds")
+				}
+				/// Parse method of this interface, without looking into parents
+				#[doc(hidden)]
+				pub fn parse_own(method_id: ::evm_coder::types::Bytes4, reader: &[u8]) -> ::evm_coder::abi::Result<Option<Self>> {
+					match method_id {
+						#(
+							#parsers,
+						)*
+						_ => {},
+					}
+					Ok(None)
+				}
+				/// Parse `i`-th method of this interface and its parents, see [`Self::selector_at`]
+				#[doc(hidden)]
+				#[allow(unused_variables)]
+				pub fn parse_at(i: usize, method_id: ::evm_coder::types::Bytes4, reader: &[u8]) -> ::evm_coder::abi::Result<Option<Self>> {
+					match i {
+						#(
+							#index_parsers,
+						)*
+						_ => {},
+					}
+					let i = i - #own_selector_count;
+					#(#parent_parse_at)*
+					Ok(None)
+				}
+				/// Fails compilation if any two methods in this interface and its parents have the same
+				/// selector. Same method, inherited multiple times, is not a collision
+				#[doc(hidden)]
+				pub const SELECTOR_COLLISION_CHECK: () = {
+					let mut i = 0;
+					while i < Self::SELECTOR_COUNT {
+						let a = u32::from_be_bytes(Self::selector_at(i).0);
+						if a == u32::from_be_bytes(::evm_coder::ERC165Call::INTERFACE_ID.0) {
+							::evm_coder::selector_collision(
+								&Self::selector_name_at(i),
+								&::evm_coder::custom_signature::SignatureUnit::new("ERC165::supports_interface (supportsInterface(bytes4))"),
+							);
+						}
+						let mut j = i + 1;
+						while j < Self::SELECTOR_COUNT {
+							if a == u32::from_be_bytes(Self::selector_at(j).0) {
+								let a_name = Self::selector_name_at(i);
+								let b_name = Self::selector_name_at(j);
+								if !a_name.is_same(&b_name) {
+									::evm_coder::selector_collision(&a_name, &b_name);
+								}
+							}
+							j += 1;
+						}
+						i += 1;
+					}
+				};
 				/// Return this call ERC165 selector
 				pub const fn interface_id() -> ::evm_coder::types::Bytes4 {
 					let mut interface_id = 0;
@@ -963,11 +1987,39 @@ impl SolidityInterface {
 					#(#inline_interface_id)*
 					::evm_coder::types::BytesFixed(u32::to_be_bytes(interface_id))
 				}
+				/// Solidity definitions of methods of this interface, including methods of `inline_is` parents
+				#[cfg(feature = "stubgen")]
+				#[allow(clippy::unused_unit)] // In case of no methods
+				pub fn solidity_functions() -> impl ::evm_coder::solidity::SolidityFunctions {
+					use evm_coder::solidity::*;
+					(#(
+						#solidity_functions,
+					)* #(
+						#solidity_inline_functions,
+					)*)
+				}
+				/// Collect solidity parents of this interface, including parents of `inline_is` parents
+				#[cfg(feature = "stubgen")]
+				pub fn solidity_is(is: &mut ::evm_coder::types::Vec<&'static str>) {
+					let names: &[&'static str] = &[#(
+						#solidity_is,
+					)* #(
+						#solidity_events_is,
+					)*];
+					for name in names {
+						if !is.contains(name) {
+							is.push(name);
+						}
+					}
+					#(#solidity_inline_parents_is)*
+				}
 				/// Generate solidity definitions for methods described in this interface
 				#[cfg(feature = "stubgen")]
 				pub fn generate_solidity_interface(tc: &evm_coder::solidity::TypeCollector, is_impl: bool) {
 					use evm_coder::solidity::*;
 					use core::fmt::Write;
+					let mut inline_is = ::evm_coder::types::Vec::new();
+					#(#solidity_inline_is)*
 					let interface = SolidityInterface {
 						docs: &[#(#docs),*],
 						name: #solidity_name,
@@ -977,16 +2029,14 @@ impl SolidityInterface {
 						)* #(
 							#solidity_events_is,
 						)* ],
-						functions: (#(
-							#solidity_functions,
-						)*),
+						functions: Self::solidity_functions(),
 					};
 
 					let mut out = ::evm_coder::types::String::new();
 					if #solidity_name.starts_with("Inline") {
 						out.push_str("/// @dev inlined interface\n");
 					}
-					let _ = interface.format(is_impl, &mut out, tc);
+					let _ = interface.format_inlined(&inline_is, is_impl, &mut out, tc);
 					tc.collect(out);
 					#(
 						#solidity_event_generators
@@ -1003,20 +2053,22 @@ impl SolidityInterface {
 			}
 			impl #gen_ref ::evm_coder::Call for #call_name #gen_ref {
 				fn parse(method_id: ::evm_coder::types::Bytes4, reader: &[u8]) -> ::evm_coder::abi::Result<Option<Self>> {
-					match method_id {
-						::evm_coder::ERC165Call::INTERFACE_ID => return Ok(
+					#[allow(clippy::let_unit_value)]
+					let () = Self::SELECTOR_COLLISION_CHECK;
+					if method_id == ::evm_coder::ERC165Call::INTERFACE_ID {
+						return Ok(
 							::evm_coder::ERC165Call::parse(method_id, reader)?
 							.map(|c| Self::ERC165Call(c, ::core::marker::PhantomData))
-						),
-						#(
-							#parsers,
-						)*
-						_ => {},
+						);
 					}
-					#(
-						#call_parse
-					)else*
-					return Ok(None);
+					#parse_lookup
+				}
+				#[allow(unused_variables)] // In case of no fallback functions
+				fn parse_fallback(input: &[u8]) -> Option<Self> {
+					#parse_fallback
+				}
+				fn parse_receive() -> Option<Self> {
+					#parse_receive
 				}
 			}
 			impl #generics #call_name #gen_ref
@@ -1036,22 +2088,61 @@ impl SolidityInterface {
 			impl #generics ::evm_coder::Callable<#call_name #gen_ref> for #name
 			#gen_where
 			{
-				#[allow(unreachable_code)] // In case of no inner calls
 				fn call(&mut self, c: ::evm_coder::types::Msg<#call_name #gen_ref>) -> ::evm_coder::ResultWithPostInfoOf<Self, ::evm_coder::types::Vec<u8>> {
+					// Events are discarded, see `Callable::call` docs
+					let mut event_sink = ::evm_coder::types::EventSink::new(::evm_coder::types::Address::zero());
+					self.call_with_events(c, &mut event_sink)
+				}
+				#[allow(unreachable_code, unused_variables)] // In case of no inner calls, or no events emitted
+				fn call_with_events(
+					&mut self,
+					c: ::evm_coder::types::Msg<#call_name #gen_ref>,
+					event_sink: &mut ::evm_coder::types::EventSink,
+				) -> ::evm_coder::ResultWithPostInfoOf<Self, ::evm_coder::types::Vec<u8>> {
+					use ::evm_coder::abi::AbiEncode;
+					// Reverted call should not emit events
+					let logs_len = event_sink.logs().len();
+					let mut dispatch = || -> ::evm_coder::ResultWithPostInfoOf<Self, ::evm_coder::types::Vec<u8>> {
+						match c.call {
+							#(
+								#call_variants,
+							)*
+							#supports_interface_call,
+							_ => {},
+						}
+						match c.call {
+							#(
+								#call_variants_this,
+							)*
+							_ => Err(<Self as ::evm_coder::Contract>::with_default_post("method is not available".into())),
+						}
+					};
+					let result = dispatch();
+					if result.is_err() {
+						event_sink.truncate(logs_len);
+					}
+					result
+				}
+			}
+			impl #generics ::evm_coder::CallableView<#call_name #gen_ref> for #name
+			#gen_where
+			{
+				#[allow(unreachable_code, unused_variables)] // In case of no inner calls
+				fn call_view(
+					&self,
+					c: ::evm_coder::types::Msg<#call_name #gen_ref>,
+				) -> ::evm_coder::ResultWithPostInfoOf<Self, ::evm_coder::types::Vec<u8>> {
 					use ::evm_coder::abi::AbiEncode;
 					match c.call {
 						#(
-							#call_variants,
+							#call_view_variants,
 						)*
-						#call_name::ERC165Call(::evm_coder::ERC165Call::SupportsInterface {interface_id}, _) => {
-							let data = <#call_name #gen_ref>::supports_interface(self, interface_id).abi_encode();
-							return Ok(<Self as ::evm_coder::Contract>::with_default_post(data));
-						}
+						#supports_interface_call,
 						_ => {},
 					}
 					match c.call {
 						#(
-							#call_variants_this,
+							#call_view_variants_this,
 						)*
 						_ => Err(<Self as ::evm_coder::Contract>::with_default_post("method is not available".into())),
 					}
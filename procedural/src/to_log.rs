@@ -1,20 +1,77 @@
 use inflector::cases;
-use quote::quote;
-use syn::{spanned::Spanned, Data, DeriveInput, Field, Fields, Ident, Variant};
+use quote::{format_ident, quote};
+use syn::{
+	parse::{Parse, ParseStream},
+	spanned::Spanned,
+	Attribute, Data, DeriveInput, Field, Fields, Ident, LitStr, Token,
+};
 
-use crate::{parse_ident_from_path, parse_ident_from_type, snake_ident_to_screaming};
+use crate::{abi_derive::extract_docs, parse_ident_from_path, snake_ident_to_screaming};
+
+mod kw {
+	syn::custom_keyword!(rename);
+}
+
+/// Event and event field attributes, specified as `#[event(...)]`
+#[derive(Default)]
+struct EventInfo {
+	rename: Option<String>,
+}
+impl Parse for EventInfo {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let mut info = Self::default();
+		while !input.is_empty() {
+			let lookahead = input.lookahead1();
+			if lookahead.peek(kw::rename) {
+				let k = input.parse::<kw::rename>()?;
+				input.parse::<Token![=]>()?;
+				if info
+					.rename
+					.replace(input.parse::<LitStr>()?.value())
+					.is_some()
+				{
+					return Err(syn::Error::new(k.span, "rename is already set"));
+				}
+			} else {
+				return Err(lookahead.error());
+			}
+
+			if input.peek(Token![,]) {
+				input.parse::<Token![,]>()?;
+			} else if !input.is_empty() {
+				return Err(syn::Error::new(input.span(), "expected end"));
+			}
+		}
+		Ok(info)
+	}
+}
+impl EventInfo {
+	fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+		let mut info = Self::default();
+		for attr in attrs.iter().filter(|attr| attr.path.is_ident("event")) {
+			let parsed = attr.parse_args::<Self>()?;
+			if let Some(rename) = parsed.rename {
+				if info.rename.replace(rename).is_some() {
+					return Err(syn::Error::new(attr.span(), "rename is already set"));
+				}
+			}
+		}
+		Ok(info)
+	}
+}
 
 struct EventField {
 	name: Ident,
 	camel_name: String,
-	ty: Ident,
+	ty: syn::Type,
 	indexed: bool,
+	docs: Vec<String>,
 }
 
 impl EventField {
 	fn try_from(field: &Field) -> syn::Result<Self> {
 		let name = field.ident.as_ref().unwrap();
-		let ty = parse_ident_from_type(&field.ty, false)?;
+		let info = EventInfo::from_attrs(&field.attrs)?;
 		let mut indexed = false;
 		for attr in &field.attrs {
 			if let Ok(ident) = parse_ident_from_path(&attr.path, false) {
@@ -25,9 +82,12 @@ impl EventField {
 		}
 		Ok(Self {
 			name: name.clone(),
-			camel_name: cases::camelcase::to_camel_case(&name.to_string()),
-			ty: ty.clone(),
+			camel_name: info
+				.rename
+				.unwrap_or_else(|| cases::camelcase::to_camel_case(&name.to_string())),
+			ty: field.ty.clone(),
 			indexed,
+			docs: extract_docs(&field.attrs)?,
 		})
 	}
 	fn expand_solidity_argument(&self) -> proc_macro2::TokenStream {
@@ -42,30 +102,58 @@ impl EventField {
 
 struct Event {
 	name: Ident,
+	solidity_name: String,
+	docs: Vec<String>,
 	name_screaming: Ident,
 	fields: Vec<EventField>,
 	selector: proc_macro2::TokenStream,
+	anonymous: bool,
+	/// Event is derived for struct, not for enum variant
+	standalone: bool,
 }
 
 impl Event {
-	fn try_from(variant: &Variant) -> syn::Result<Self> {
-		let name = &variant.ident;
-		let name_lit = proc_macro2::Literal::string(name.to_string().as_str());
+	fn try_from(
+		name: &Ident,
+		attrs: &[Attribute],
+		fields: &Fields,
+		standalone: bool,
+	) -> syn::Result<Self> {
+		let info = EventInfo::from_attrs(attrs)?;
+		let solidity_name = info.rename.unwrap_or_else(|| name.to_string());
+		let name_lit = proc_macro2::Literal::string(&solidity_name);
 		let name_screaming = snake_ident_to_screaming(name);
 
-		let Fields::Named(named) = &variant.fields else {
-			return Err(syn::Error::new(
-				variant.fields.span(),
-				"expected named fields",
-			));
+		let Fields::Named(named) = fields else {
+			return Err(syn::Error::new(fields.span(), "expected named fields"));
 		};
+		let span = fields.span();
 		let mut fields = Vec::new();
 		for field in &named.named {
 			fields.push(EventField::try_from(field)?);
 		}
-		if fields.iter().filter(|f| f.indexed).count() > 3 {
+		for field in fields.iter().filter(|f| f.indexed) {
+			let name = field.name.to_string();
+			if ["topics", "matches", "might_match"].contains(&name.as_str()) {
+				return Err(syn::Error::new(
+					field.name.span(),
+					format!(
+						"indexed field `{name}` conflicts with method of generated event filter"
+					),
+				));
+			}
+		}
+		let anonymous = attrs.iter().any(|attr| attr.path.is_ident("anonymous"));
+		let indexed_count = fields.iter().filter(|f| f.indexed).count();
+		if anonymous && indexed_count > 4 {
 			return Err(syn::Error::new(
-				variant.fields.span(),
+				span,
+				"anonymous events can have at most 4 indexed fields",
+			));
+		}
+		if !anonymous && indexed_count > 3 {
+			return Err(syn::Error::new(
+				span,
 				"events can have at most 4 indexed fields (1 indexed field is reserved for event signature)"
 			));
 		}
@@ -100,25 +188,44 @@ impl Event {
 
 		Ok(Self {
 			name: name.clone(),
+			solidity_name,
+			docs: extract_docs(attrs)?,
 			name_screaming,
 			fields,
 			selector,
+			anonymous,
+			standalone,
 		})
 	}
 
-	fn expand_serializers(&self) -> proc_macro2::TokenStream {
+	/// Path to construct or match this event
+	fn path(&self) -> proc_macro2::TokenStream {
 		let name = &self.name;
+		if self.standalone {
+			quote! {Self}
+		} else {
+			quote! {Self::#name}
+		}
+	}
+
+	fn expand_serializers(&self) -> proc_macro2::TokenStream {
+		let path = self.path();
 		let name_screaming = &self.name_screaming;
 		let fields = self.fields.iter().map(|f| &f.name);
 
 		let indexed = self.fields.iter().filter(|f| f.indexed).map(|f| &f.name);
 		let plain = self.fields.iter().filter(|f| !f.indexed).map(|f| &f.name);
+		let signature_topic = (!self.anonymous).then(|| {
+			quote! {
+				topics.push(::evm_coder::types::Topic::from(Self::#name_screaming));
+			}
+		});
 
 		quote! {
-			Self::#name {#(
+			#path {#(
 				#fields,
 			)*} => {
-				topics.push(::evm_coder::types::Topic::from(Self::#name_screaming));
+				#signature_topic
 				#(
 					topics.push(#indexed.to_topic());
 				)*
@@ -131,6 +238,133 @@ impl Event {
 		}
 	}
 
+	/// Returns expression of `Result<Self, FromLogError>` type
+	fn expand_deserializer(&self) -> proc_macro2::TokenStream {
+		let path = self.path();
+		let fields = self.fields.iter().map(|f| &f.name);
+
+		let indexed = self.fields.iter().filter(|f| f.indexed);
+		let topic_offset = usize::from(!self.anonymous);
+		let topic_count = indexed.clone().count() + topic_offset;
+		let indexed = indexed.enumerate().map(|(i, f)| {
+			let name = &f.name;
+			let ty = &f.ty;
+			let i = i + topic_offset;
+			quote! {
+				let #name = <#ty as ::evm_coder::events::FromTopic>::from_topic(&log.topics[#i])
+					.map_err(::evm_coder::events::FromLogError::Topic)?;
+			}
+		});
+		let plain = self.fields.iter().filter(|f| !f.indexed).map(|f| &f.name);
+		let plain_ty = self.fields.iter().filter(|f| !f.indexed).map(|f| &f.ty);
+
+		quote! {
+			(|| {
+				if log.topics.len() != #topic_count {
+					return Err(::evm_coder::events::FromLogError::TopicCount);
+				}
+				#(#indexed)*
+				let (#(#plain,)*) = <(#(#plain_ty,)*)>::abi_decode(&log.data)
+					.map_err(::evm_coder::events::FromLogError::Data)?;
+				Ok(#path {#(
+					#fields,
+				)*})
+			})()
+		}
+	}
+
+	fn filter_name(&self, enum_name: &Ident) -> Ident {
+		if self.standalone {
+			format_ident!("{}Filter", self.name)
+		} else {
+			format_ident!("{}{}Filter", enum_name, self.name)
+		}
+	}
+
+	fn expand_filter_constructor(&self, enum_name: &Ident) -> proc_macro2::TokenStream {
+		let filter_name = self.filter_name(enum_name);
+		let fn_name = if self.standalone {
+			format_ident!("filter")
+		} else {
+			format_ident!(
+				"{}_filter",
+				cases::snakecase::to_snake_case(&self.name.to_string())
+			)
+		};
+		let docs = format!(" Filter of `{}` events", self.name);
+		let name_screaming = &self.name_screaming;
+		let signature_topic = (!self.anonymous).then(|| {
+			quote! {Some(::evm_coder::types::Topic::from(Self::#name_screaming)),}
+		});
+		let indexed = self
+			.fields
+			.iter()
+			.filter(|f| f.indexed)
+			.map(|_| quote! {None,});
+		quote! {
+			#[doc = #docs]
+			pub fn #fn_name() -> #filter_name {
+				#filter_name(::evm_coder::events::TopicFilter {
+					topics: ::evm_coder::types::Vec::from([#signature_topic #(#indexed)*]),
+				})
+			}
+		}
+	}
+
+	fn expand_filter(&self, enum_name: &Ident, vis: &syn::Visibility) -> proc_macro2::TokenStream {
+		let filter_name = self.filter_name(enum_name);
+		let docs = format!(" Filter of `{}` events, see [`{enum_name}`]", self.name);
+		let topic_offset = usize::from(!self.anonymous);
+		let setters = self
+			.fields
+			.iter()
+			.filter(|f| f.indexed)
+			.enumerate()
+			.map(|(i, f)| {
+				let name = &f.name;
+				let ty = &f.ty;
+				let i = i + topic_offset;
+				let docs = format!(" Match only events with specified `{name}`");
+				quote! {
+					#[doc = #docs]
+					pub fn #name(mut self, value: #ty) -> Self {
+						self.0.topics[#i] = Some(::evm_coder::events::ToTopic::to_topic(&value));
+						self
+					}
+				}
+			});
+		quote! {
+			#[doc = #docs]
+			#[derive(Debug, Clone, PartialEq, Eq)]
+			#vis struct #filter_name(::evm_coder::events::TopicFilter);
+
+			impl #filter_name {
+				#(#setters)*
+
+				/// Topics to match, `None` matches any value
+				pub fn topics(&self) -> &[Option<::evm_coder::types::Topic>] {
+					&self.0.topics
+				}
+
+				/// Check if log satisfies this filter
+				pub fn matches(&self, log: &::evm_coder::ethereum::Log) -> bool {
+					self.0.matches(log)
+				}
+
+				/// Check if log satisfying this filter might be included in block/receipt with this bloom
+				pub fn might_match(&self, bloom: &::evm_coder::events::LogsBloom) -> bool {
+					self.0.might_match(bloom)
+				}
+			}
+
+			impl From<#filter_name> for ::evm_coder::events::TopicFilter {
+				fn from(filter: #filter_name) -> Self {
+					filter.0
+				}
+			}
+		}
+	}
+
 	fn expand_consts(&self) -> proc_macro2::TokenStream {
 		let name_screaming = &self.name_screaming;
 		let selector = &self.selector;
@@ -141,11 +375,25 @@ impl Event {
 	}
 
 	fn expand_solidity_function(&self) -> proc_macro2::TokenStream {
-		let name = self.name.to_string();
+		let name = &self.solidity_name;
+		let mut docs = self.docs.clone();
+		// Field docs are appended as NatSpec parameter descriptions
+		for field in &self.fields {
+			for (i, doc) in field.docs.iter().enumerate() {
+				if i == 0 {
+					docs.push(format!(" @param {}{doc}", field.camel_name));
+				} else {
+					docs.push(doc.clone());
+				}
+			}
+		}
 		let args = self.fields.iter().map(EventField::expand_solidity_argument);
+		let anonymous = self.anonymous;
 		quote! {
 			SolidityEvent {
+				docs: &[#(#docs),*],
 				name: #name,
+				anonymous: #anonymous,
 				args: (
 					#(
 						#args,
@@ -158,21 +406,46 @@ impl Event {
 
 pub struct Events {
 	name: Ident,
+	vis: syn::Visibility,
+	docs: Vec<String>,
 	events: Vec<Event>,
 }
 
 impl Events {
 	pub fn try_from(data: &DeriveInput) -> syn::Result<Self> {
 		let name = &data.ident;
-		let Data::Enum(en) = &data.data else {
-			return Err(syn::Error::new(data.span(), "expected enum"));
-		};
-		let mut events = Vec::new();
-		for variant in &en.variants {
-			events.push(Event::try_from(variant)?);
+		if !data.generics.params.is_empty() {
+			return Err(syn::Error::new(
+				data.generics.span(),
+				"generic parameters are not supported for events, use concrete field types",
+			));
 		}
+		let mut events = Vec::new();
+		let docs = match &data.data {
+			Data::Enum(en) => {
+				for variant in &en.variants {
+					events.push(Event::try_from(
+						&variant.ident,
+						&variant.attrs,
+						&variant.fields,
+						false,
+					)?);
+				}
+				extract_docs(&data.attrs)?
+			}
+			// Struct docs are describing the event itself
+			Data::Struct(st) => {
+				events.push(Event::try_from(name, &data.attrs, &st.fields, true)?);
+				Vec::new()
+			}
+			Data::Union(_) => {
+				return Err(syn::Error::new(data.span(), "expected enum or struct"));
+			}
+		};
 		Ok(Self {
 			name: name.clone(),
+			vis: data.vis.clone(),
+			docs,
 			events,
 		})
 	}
@@ -180,8 +453,14 @@ impl Events {
 		let name = &self.name;
 
 		let consts = self.events.iter().map(Event::expand_consts);
+		let filter_constructors = self
+			.events
+			.iter()
+			.map(|e| e.expand_filter_constructor(name));
+		let filters = self.events.iter().map(|e| e.expand_filter(name, &self.vis));
 		let serializers = self.events.iter().map(Event::expand_serializers);
 		let solidity_name = self.name.to_string();
+		let docs = &self.docs;
 		let solidity_functions = self.events.iter().map(Event::expand_solidity_function);
 
 		quote! {
@@ -190,13 +469,17 @@ impl Events {
 					#consts
 				)*
 
+				#(
+					#filter_constructors
+				)*
+
 				/// Generate solidity definitions for methods described in this interface
 				#[cfg(feature = "stubgen")]
 				pub fn generate_solidity_interface(tc: &evm_coder::solidity::TypeCollector, is_impl: bool) {
 					use evm_coder::solidity::*;
 					use core::fmt::Write;
 					let interface = SolidityInterface {
-						docs: &[],
+						docs: &[#(#docs),*],
 						selector: ::evm_coder::types::BytesFixed([0; 4]),
 						name: #solidity_name,
 						is: &[],
@@ -211,6 +494,10 @@ impl Events {
 				}
 			}
 
+			#(
+				#filters
+			)*
+
 			#[automatically_derived]
 			impl ::evm_coder::events::ToLog for #name {
 				fn to_log(&self, contract: Address) -> ::evm_coder::ethereum::Log {
@@ -226,4 +513,54 @@ impl Events {
 			}
 		}
 	}
+
+	pub fn expand_from_log(&self) -> proc_macro2::TokenStream {
+		let name = &self.name;
+		let deserializers = self.events.iter().filter(|e| !e.anonymous).map(|e| {
+			let name_screaming = &e.name_screaming;
+			let deserializer = e.expand_deserializer();
+			quote! {
+				if log.topics.first().map(|t| t.0) == Some(Self::#name_screaming) {
+					return #deserializer.map(Some);
+				}
+			}
+		});
+		// Anonymous events can't be told apart by selector, first one, which is successfully
+		// decoded from the log, wins
+		let anonymous_deserializers = self
+			.events
+			.iter()
+			.filter(|e| e.anonymous)
+			.map(Event::expand_deserializer)
+			.collect::<Vec<_>>();
+		let from_log_anonymous = (!anonymous_deserializers.is_empty()).then(|| {
+			quote! {
+				fn from_log_anonymous(log: &::evm_coder::ethereum::Log) -> Option<Self> {
+					use ::evm_coder::abi::AbiDecode;
+					#(
+						if let Ok(event) = #anonymous_deserializers {
+							return Some(event);
+						}
+					)*
+					None
+				}
+			}
+		});
+
+		quote! {
+			#[automatically_derived]
+			impl ::evm_coder::events::FromLog for #name {
+				fn from_log(
+					log: &::evm_coder::ethereum::Log,
+				) -> ::core::result::Result<Option<Self>, ::evm_coder::events::FromLogError> {
+					use ::evm_coder::abi::AbiDecode;
+					#(
+						#deserializers
+					)*
+					Ok(None)
+				}
+				#from_log_anonymous
+			}
+		}
+	}
 }
@@ -15,20 +15,23 @@ impl<T: AbiType> AbiType for Vec<T> {
 	const HEAD_WORDS: u32 = 1;
 	const IS_DYNAMIC: bool = true;
 }
+fn encode_slice<T: AbiEncode>(data: &[T], out: &mut AbiEncoder) {
+	(data.len() as u32).enc(out);
+	if T::IS_DYNAMIC {
+		out.reserve_head(data.len() as u32);
+		for v in data {
+			(data.len() as u32 * ABI_WORD_SIZE + out.tail_size()).enc(out);
+			out.encode_tail(v);
+		}
+	} else {
+		for v in data {
+			out.encode_tail(v);
+		}
+	}
+}
 impl<T: AbiEncode> AbiEncode for Vec<T> {
 	fn enc(&self, out: &mut AbiEncoder) {
-		(self.len() as u32).enc(out);
-		if T::IS_DYNAMIC {
-			out.reserve_head(self.len() as u32);
-			for v in self {
-				(self.len() as u32 * ABI_WORD_SIZE + out.tail_size()).enc(out);
-				out.encode_tail(v);
-			}
-		} else {
-			for v in self {
-				out.encode_tail(v);
-			}
-		}
+		encode_slice(self, out);
 	}
 }
 impl<T: AbiDecode> AbiDecode for Vec<T> {
@@ -330,6 +333,46 @@ impl<T: AbiDecode + AbiDecodeZero> AbiDecode for Option<T> {
 	}
 }
 
+impl<T: AbiType> AbiType for OptionAsArray<T> {
+	const SIGNATURE: SignatureUnit = <Vec<T>>::SIGNATURE;
+	const HEAD_WORDS: u32 = <Vec<T>>::HEAD_WORDS;
+	const IS_DYNAMIC: bool = <Vec<T>>::IS_DYNAMIC;
+}
+impl<T: AbiEncode> AbiEncode for OptionAsArray<T> {
+	fn enc(&self, out: &mut AbiEncoder) {
+		encode_slice(self.0.as_slice(), out);
+	}
+}
+impl<T: AbiDecode> AbiDecode for OptionAsArray<T> {
+	fn dec(input: &mut AbiDecoder<'_>) -> Result<Self> {
+		let mut values = <Vec<T>>::dec(input)?;
+		if values.len() > 1 {
+			return Err(Error::InvalidRange);
+		}
+		Ok(Self(values.pop()))
+	}
+}
+
+impl<T: AbiType> AbiType for OptionAsZero<T> {
+	const SIGNATURE: SignatureUnit = T::SIGNATURE;
+	const HEAD_WORDS: u32 = T::HEAD_WORDS;
+	const IS_DYNAMIC: bool = T::IS_DYNAMIC;
+}
+impl<T: AbiEncodeZero> AbiEncode for OptionAsZero<T> {
+	fn enc(&self, out: &mut AbiEncoder) {
+		match &self.0 {
+			Some(v) => v.enc(out),
+			None => T::enc_zero(out),
+		}
+	}
+}
+impl<T: AbiDecode + Default + PartialEq> AbiDecode for OptionAsZero<T> {
+	fn dec(input: &mut AbiDecoder<'_>) -> Result<Self> {
+		let value = T::dec(input)?;
+		Ok(Self((value != T::default()).then_some(value)))
+	}
+}
+
 impl<T: AbiType> AbiType for Zero<T> {
 	const SIGNATURE: SignatureUnit = T::SIGNATURE;
 	const HEAD_WORDS: u32 = T::HEAD_WORDS;
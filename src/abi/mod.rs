@@ -6,6 +6,7 @@ use core::{fmt, mem, result};
 
 pub use traits::*;
 mod impls;
+pub mod with;
 
 #[cfg(test)]
 mod test;
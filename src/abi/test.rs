@@ -592,3 +592,90 @@ fn encode_decode_option_string_none() {
 		),
 	);
 }
+
+#[test]
+fn encode_decode_option_as_array_uint8_some() {
+	test_impl::<(OptionAsArray<u8>,)>(
+		0xdeadbeef,
+		(OptionAsArray(Some(44)),),
+		&hex!(
+			"
+				deadbeef
+				0000000000000000000000000000000000000000000000000000000000000020 // Offset of array
+				0000000000000000000000000000000000000000000000000000000000000001 // Array length
+				000000000000000000000000000000000000000000000000000000000000002c
+			"
+		),
+	);
+}
+
+#[test]
+fn encode_decode_option_as_array_string_none() {
+	test_impl::<(OptionAsArray<String>,)>(
+		0xdeadbeef,
+		(OptionAsArray(None),),
+		&hex!(
+			"
+				deadbeef
+				0000000000000000000000000000000000000000000000000000000000000020 // Offset of array
+				0000000000000000000000000000000000000000000000000000000000000000 // Array length
+			"
+		),
+	);
+}
+
+#[test]
+fn decode_option_as_array_rejects_multiple_values() {
+	let encoded = (vec![1u8, 2u8],).abi_encode();
+	assert!(<(OptionAsArray<u8>,)>::abi_decode(&encoded).is_err());
+}
+
+#[test]
+fn encode_decode_option_as_zero_uint8_some() {
+	test_impl::<OptionAsZero<u8>>(
+		0xdeadbeef,
+		OptionAsZero(Some(44)),
+		&hex!(
+			"
+				deadbeef
+				000000000000000000000000000000000000000000000000000000000000002c
+			"
+		),
+	);
+}
+
+#[test]
+fn encode_decode_option_as_zero_address_none() {
+	test_impl::<OptionAsZero<Address>>(
+		0xdeadbeef,
+		OptionAsZero(None),
+		&hex!(
+			"
+				deadbeef
+				0000000000000000000000000000000000000000000000000000000000000000
+			"
+		),
+	);
+}
+
+#[test]
+fn encode_decode_option_as_zero_string_none() {
+	test_impl::<(OptionAsZero<String>,)>(
+		0xdeadbeef,
+		(OptionAsZero(None),),
+		&hex!(
+			"
+				deadbeef
+				0000000000000000000000000000000000000000000000000000000000000020 // Offset of string
+				0000000000000000000000000000000000000000000000000000000000000000 // String length
+			"
+		),
+	);
+}
+
+#[test]
+fn option_representation_signatures() {
+	assert_eq!(<Option<u8>>::signature(), "(bool,uint8)");
+	assert_eq!(<OptionAsArray<u8>>::signature(), "uint8[]");
+	assert_eq!(<OptionAsZero<u8>>::signature(), "uint8");
+}
@@ -0,0 +1,43 @@
+//! Conversions, usable in `#[abi(with = ...)]` field attribute of [`AbiCoder`](crate::AbiCoder) derive
+
+/// Encode `Vec<u8>` as solidity `bytes`, instead of `uint8[]`
+pub mod bytes {
+	#[cfg(not(feature = "std"))]
+	use alloc::vec::Vec;
+
+	use crate::{
+		abi::{AbiEncode, AbiEncoder, AbiType, Result, ABI_WORD_SIZE},
+		custom_signature::SignatureUnit,
+		types::Bytes,
+	};
+
+	pub type Abi = Bytes;
+
+	/// Borrowed [`Bytes`], used to encode field without copying it
+	pub struct AbiRef<'a>(pub &'a [u8]);
+	impl AbiType for AbiRef<'_> {
+		const SIGNATURE: SignatureUnit = Bytes::SIGNATURE;
+		const HEAD_WORDS: u32 = Bytes::HEAD_WORDS;
+		const IS_DYNAMIC: bool = Bytes::IS_DYNAMIC;
+	}
+	impl AbiEncode for AbiRef<'_> {
+		fn enc(&self, out: &mut AbiEncoder) {
+			(self.0.len() as u32).enc(out);
+			for ele in self.0.chunks(32) {
+				let mut word = [0; ABI_WORD_SIZE as usize];
+				word[0..ele.len()].copy_from_slice(ele);
+				out.append_tail(word);
+			}
+		}
+	}
+
+	#[allow(clippy::ptr_arg)]
+	pub fn encode(value: &Vec<u8>) -> AbiRef<'_> {
+		AbiRef(value)
+	}
+
+	#[allow(clippy::unnecessary_wraps)]
+	pub fn decode(value: Abi) -> Result<Vec<u8>> {
+		Ok(value.0)
+	}
+}
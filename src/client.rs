@@ -0,0 +1,70 @@
+//! Typed clients for contracts, generated by [`crate::solidity_interface`] with `client = Name`
+//! option
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::fmt;
+
+use primitive_types::U256;
+
+use crate::{abi, types::Address};
+
+/// Transport, used by generated clients to call contracts
+///
+/// It may submit transactions to a real chain, or dispatch calls to local
+/// [`crate::Callable`] implementation, which is useful for end-to-end tests
+pub trait Transport {
+	/// Transport error
+	type Error;
+	/// Call contract at address `to` with ABI encoded call `data`, transferring `value` to it.
+	/// Returns ABI encoded call result
+	fn call(&mut self, to: Address, data: Vec<u8>, value: U256) -> Result<Vec<u8>, Self::Error>;
+}
+
+impl<T: Transport + ?Sized> Transport for &mut T {
+	type Error = T::Error;
+	fn call(&mut self, to: Address, data: Vec<u8>, value: U256) -> Result<Vec<u8>, Self::Error> {
+		(**self).call(to, data, value)
+	}
+}
+
+/// Call enum of interface, which has generated client
+///
+/// Clients of interfaces, inheriting this one with `is` or `inline_is`, use it to provide
+/// accessor for client of inherited interface
+pub trait InterfaceClient<T> {
+	/// Generated client type
+	type Client;
+	/// Create client for contract with specified address
+	fn client(transport: T, address: Address) -> Self::Client;
+}
+
+/// Error of call made using generated client
+#[derive(Debug)]
+pub enum ClientError<E> {
+	/// Transport failed to make the call
+	Transport(E),
+	/// Call result can't be decoded
+	Decode(abi::Error),
+}
+impl<E: fmt::Display> fmt::Display for ClientError<E> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			ClientError::Transport(e) => write!(f, "transport error: {e}"),
+			ClientError::Decode(e) => write!(f, "bad result: {e}"),
+		}
+	}
+}
+
+/// Return type of contract method, used by generated client and [`crate::descriptor`] to find
+/// out type of call result. Method may return either `Result<T>`, or infallible `T`
+pub trait MethodResult {
+	/// Type of successful call result
+	type Value;
+}
+impl<T, E> MethodResult for Result<T, E> {
+	type Value = T;
+}
+impl<T: abi::AbiType> MethodResult for T {
+	type Value = T;
+}
@@ -95,6 +95,21 @@ impl SignatureUnit {
 	pub fn as_str(&self) -> Option<&str> {
 		core::str::from_utf8(&self.data[0..self.len]).ok()
 	}
+	/// Compare signatures in const context
+	#[must_use]
+	pub const fn is_same(&self, other: &SignatureUnit) -> bool {
+		if self.len != other.len {
+			return false;
+		}
+		let mut i = 0;
+		while i < self.len {
+			if self.data[i] != other.data[i] {
+				return false;
+			}
+			i += 1;
+		}
+		true
+	}
 }
 
 /// ### Macro to create signatures of types and functions.
@@ -0,0 +1,124 @@
+//! Static description of interfaces, generated by [`crate::solidity_interface`] as
+//! `DESCRIPTOR` constant of call enum, i.e `ERC20Call::DESCRIPTOR`
+//!
+//! Unlike solidity stubs, descriptors are available without `stubgen` feature, and may be used
+//! to inspect interfaces at runtime
+
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+
+use crate::{custom_signature::SignatureUnit, types::Bytes4};
+
+/// Interface, described by [`crate::solidity_interface`] macro invocation
+#[derive(Debug)]
+pub struct InterfaceDescriptor {
+	/// Solidity name of interface
+	pub name: &'static str,
+	/// ERC165 interface id, see [`crate::ERC165Call`]
+	pub interface_id: Bytes4,
+	/// Doc comments of interface, without leading `///`
+	pub docs: &'static [&'static str],
+	/// Methods, which have selectors. Fallback and receive functions are listed separately
+	pub methods: &'static [MethodDescriptor],
+	/// Function, declared with `#[solidity(fallback)]`
+	pub fallback: Option<SpecialMethodDescriptor>,
+	/// Function, declared with `#[solidity(receive)]`
+	pub receive: Option<SpecialMethodDescriptor>,
+	/// Interfaces inherited with `is`
+	pub parents: &'static [&'static InterfaceDescriptor],
+	/// Interfaces inherited with `inline_is`, their methods are included into `interface_id`
+	pub inline_parents: &'static [&'static InterfaceDescriptor],
+}
+
+impl InterfaceDescriptor {
+	/// Find method of this interface or its parents by selector
+	#[must_use]
+	pub fn find_method(&self, selector: Bytes4) -> Option<&'static MethodDescriptor> {
+		self.iter_methods().find(|m| m.selector == selector)
+	}
+
+	/// Iterate over methods of this interface and all of its parents, depth first.
+	/// Methods of interfaces, inherited multiple times, are visited multiple times
+	pub fn iter_methods(&self) -> impl Iterator<Item = &'static MethodDescriptor> + '_ {
+		let parents: Box<dyn Iterator<Item = &'static MethodDescriptor>> = Box::new(
+			self.inline_parents
+				.iter()
+				.chain(self.parents.iter())
+				.flat_map(|p| p.iter_methods()),
+		);
+		self.methods.iter().chain(parents)
+	}
+}
+
+/// Method of interface
+#[derive(Debug)]
+pub struct MethodDescriptor {
+	/// Rust name of method
+	pub name: &'static str,
+	/// Solidity name of method, may be changed with `rename_selector`
+	pub solidity_name: &'static str,
+	/// Method selector
+	pub selector: Bytes4,
+	/// Signature, used for selector calculation, i.e `transfer(address,uint256)`
+	pub signature: &'static SignatureUnit,
+	/// Arguments of method, excluding `Caller`, `Value` and `EventSink`
+	pub args: &'static [ArgDescriptor],
+	/// Return values of method, empty if method returns `()`. Values, specified with
+	/// `#[solidity(returns(...))]`, are listed separately, unnamed ones have empty name
+	pub returns: &'static [ArgDescriptor],
+	/// State mutability
+	pub mutability: Mutability,
+	/// Can method receive value
+	pub is_payable: bool,
+	/// Is method hidden in solidity stubs with `#[solidity(hide)]`
+	pub hide: bool,
+	/// Doc comments of method, without leading `///`
+	pub docs: &'static [&'static str],
+}
+
+impl MethodDescriptor {
+	/// Signature, used for selector calculation, as string
+	#[must_use]
+	pub fn signature(&self) -> &'static str {
+		self.signature.as_str().expect("signature is valid utf-8")
+	}
+}
+
+/// Fallback or receive function of interface, which has no selector
+#[derive(Debug)]
+pub struct SpecialMethodDescriptor {
+	/// Rust name of method
+	pub name: &'static str,
+	/// Can method receive value, always true for receive function
+	pub is_payable: bool,
+	/// Doc comments of method, without leading `///`
+	pub docs: &'static [&'static str],
+}
+
+/// Argument or return value of interface method
+#[derive(Debug)]
+pub struct ArgDescriptor {
+	/// Solidity name of argument, empty for unnamed return values
+	pub name: &'static str,
+	/// ABI type of argument, i.e `uint256` or `(address,uint256)[]`
+	pub ty: &'static SignatureUnit,
+}
+
+impl ArgDescriptor {
+	/// ABI type of argument as string
+	#[must_use]
+	pub fn ty(&self) -> &'static str {
+		self.ty.as_str().expect("signature is valid utf-8")
+	}
+}
+
+/// State mutability of method, same as solidity one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mutability {
+	/// Method doesn't read contract state
+	Pure,
+	/// Method only reads contract state
+	View,
+	/// Method may modify contract state
+	Mutable,
+}
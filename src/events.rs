@@ -1,7 +1,15 @@
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
 use ethereum::Log;
+use impl_trait_for_tuples::impl_for_tuples;
 use primitive_types::{H160, H256, U256};
 
-use crate::types::Address;
+use crate::{
+	abi::{self, AbiDecode, AbiEncode},
+	types::{Address, Bytes, BytesFixed, OptionAsArray, OptionAsZero, Zero},
+};
 
 /// Implementation of this trait should not be written manually,
 /// instead use [`crate::ToLog`] proc macros.
@@ -14,41 +22,416 @@ pub trait ToLog {
 	fn to_log(&self, contract: H160) -> Log;
 }
 
+/// Implementation of this trait should not be written manually,
+/// instead use [`crate::FromLog`] proc macros.
+pub trait FromLog: Sized {
+	/// Parse event from [`ethereum::Log`].
+	/// Returns `Ok(None)` if log was emitted for other event
+	/// Anonymous events are not matched, use [`FromLog::from_log_anonymous`] for them.
+	fn from_log(log: &Log) -> Result<Option<Self>, FromLogError>;
+	/// Parse anonymous event from [`ethereum::Log`].
+	/// Anonymous events have no selector, so the first one, which is successfully decoded
+	/// from log, is returned. Any log of matching shape is accepted, so this method should
+	/// only be used for logs, which are known to be emitted for anonymous events
+	fn from_log_anonymous(_log: &Log) -> Option<Self> {
+		None
+	}
+}
+
+/// Collects events, emitted by contract methods
+///
+/// Methods, declared in [`crate::solidity_interface`], may take `&mut EventSink` argument,
+/// which is not a part of solidity signature, and emit events into it.
+/// Emitted logs are collected by caller of [`crate::Callable::call_with_events`],
+/// logs of the call, which has returned error, are dropped from the sink
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventSink {
+	contract: H160,
+	logs: Vec<Log>,
+}
+impl EventSink {
+	/// Create sink for events of contract with specified address
+	pub fn new(contract: H160) -> Self {
+		Self {
+			contract,
+			logs: Vec::new(),
+		}
+	}
+	/// Address of contract, which emits events
+	pub fn contract(&self) -> H160 {
+		self.contract
+	}
+	/// Emit event
+	pub fn emit(&mut self, event: impl ToLog) {
+		self.logs.push(event.to_log(self.contract));
+	}
+	/// Emitted logs, in emission order
+	pub fn logs(&self) -> &[Log] {
+		&self.logs
+	}
+	/// Discard logs, emitted after first `len` logs
+	///
+	/// Used to revert events of failed call, events of which should not be observed
+	pub fn truncate(&mut self, len: usize) {
+		self.logs.truncate(len);
+	}
+	/// Take emitted logs, in emission order
+	pub fn into_logs(self) -> Vec<Log> {
+		self.logs
+	}
+}
+
+/// Log matches event selector, but can't be parsed
+#[derive(Debug)]
+pub enum FromLogError {
+	/// Number of topics doesn't match the number of indexed fields
+	TopicCount,
+	/// Indexed field can't be decoded from topic
+	Topic(abi::Error),
+	/// Not indexed fields can't be decoded from log data
+	Data(abi::Error),
+}
+impl fmt::Display for FromLogError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			FromLogError::TopicCount => write!(f, "unexpected topic count"),
+			FromLogError::Topic(e) => write!(f, "bad topic: {e}"),
+			FromLogError::Data(e) => write!(f, "bad data: {e}"),
+		}
+	}
+}
+
+/// Filter of logs by topics, generated for every event by [`crate::ToLog`] macro
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TopicFilter {
+	/// Expected topic values, `None` matches any value
+	pub topics: Vec<Option<H256>>,
+}
+impl TopicFilter {
+	/// Check if log topics satisfy this filter
+	pub fn matches(&self, log: &Log) -> bool {
+		log.topics.len() == self.topics.len()
+			&& self
+				.topics
+				.iter()
+				.zip(&log.topics)
+				.all(|(expected, topic)| expected.is_none() || *expected == Some(*topic))
+	}
+
+	/// Check if log satisfying this filter might be included in block/receipt with this bloom
+	///
+	/// Bloom may give false positives, but never false negatives
+	pub fn might_match(&self, bloom: &LogsBloom) -> bool {
+		self.topics
+			.iter()
+			.flatten()
+			.all(|topic| bloom.contains_topic(topic))
+	}
+}
+
+/// 2048-bit bloom filter of logs emitted in transaction receipt or block,
+/// as defined in Ethereum Yellow Paper (section 4.3.1)
+///
+/// Both contract address and every topic of log are accrued into bloom,
+/// so it can be queried for event selectors (i.e `Events::TRANSFER`) and indexed field values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogsBloom(pub [u8; 256]);
+impl Default for LogsBloom {
+	fn default() -> Self {
+		Self([0; 256])
+	}
+}
+impl LogsBloom {
+	/// Compute bloom of all passed logs
+	pub fn from_logs<'a>(logs: impl IntoIterator<Item = &'a Log>) -> Self {
+		let mut bloom = Self::default();
+		for log in logs {
+			bloom.accrue_log(log);
+		}
+		bloom
+	}
+
+	/// Add log address and topics to bloom
+	pub fn accrue_log(&mut self, log: &Log) {
+		self.accrue(&log.address.0);
+		for topic in &log.topics {
+			self.accrue(&topic.0);
+		}
+	}
+
+	/// Add raw input to bloom
+	pub fn accrue(&mut self, input: &[u8]) {
+		for (byte, mask) in Self::bits(input) {
+			self.0[byte] |= mask;
+		}
+	}
+
+	/// Merge other bloom into this one
+	pub fn accrue_bloom(&mut self, other: &LogsBloom) {
+		for (a, b) in self.0.iter_mut().zip(other.0.iter()) {
+			*a |= b;
+		}
+	}
+
+	/// Check if raw input might be accrued in this bloom
+	pub fn contains(&self, input: &[u8]) -> bool {
+		Self::bits(input).all(|(byte, mask)| self.0[byte] & mask != 0)
+	}
+
+	/// Check if log with this topic might be accrued in this bloom
+	pub fn contains_topic(&self, topic: &H256) -> bool {
+		self.contains(&topic.0)
+	}
+
+	/// Check if log emitted by this contract might be accrued in this bloom
+	pub fn contains_address(&self, address: &H160) -> bool {
+		self.contains(&address.0)
+	}
+
+	/// Byte index and mask of every bit set for input,
+	/// which are the low 11 bits of the first three pairs of bytes of input keccak256 hash
+	fn bits(input: &[u8]) -> impl Iterator<Item = (usize, u8)> {
+		let hash = hash_topic(input);
+		(0..3).map(move |i| {
+			let bit = (usize::from(hash.0[i * 2]) << 8 | usize::from(hash.0[i * 2 + 1])) & 2047;
+			(255 - bit / 8, 1 << (bit % 8))
+		})
+	}
+}
+
 /// Only items implementing `ToTopic` may be used as `#[indexed]` field
 /// in [`crate::ToLog`] macro usage.
 ///
+/// Value types are stored in topics as is, and reference types (strings, bytes, arrays and structs)
+/// are stored as keccak256 hash of their [`EncodeInPlace`] encoding.
+///
 /// See also (solidity docs on events)[<https://docs.soliditylang.org/en/develop/contracts.html#events>]
 pub trait ToTopic {
 	/// Convert value to topic to be used in [`ethereum::Log`]
 	fn to_topic(&self) -> H256;
 }
 
+/// Only items implementing `FromTopic` may be used as `#[indexed]` field
+/// in [`crate::FromLog`] macro usage.
+///
+/// Reference types are hashed in topics, thus can't be restored.
+pub trait FromTopic: Sized {
+	/// Restore value from topic of [`ethereum::Log`]
+	fn from_topic(topic: &H256) -> abi::Result<Self>;
+}
+
+/// Encoding of values, which is hashed to get topic of indexed reference type
+///
+/// Unlike ABI encoding, there is no offsets and length prefixes, every value is padded to
+/// a multiple of 32 bytes, and array elements/struct fields are concatenated.
+///
+/// See also [solidity docs on indexed parameters encoding](https://docs.soliditylang.org/en/latest/abi-spec.html#encoding-of-indexed-event-parameters)
+pub trait EncodeInPlace {
+	fn encode_in_place(&self, out: &mut Vec<u8>);
+}
+
+/// Topic of indexed reference type
+pub fn hash_topic(data: &[u8]) -> H256 {
+	H256(sha3_const::Keccak256::new().update(data).finalize())
+}
+
+/// Topic of reference type, which is hashed [`EncodeInPlace`] encoding
+pub fn reference_topic<T: EncodeInPlace>(value: &T) -> H256 {
+	let mut out = Vec::new();
+	value.encode_in_place(&mut out);
+	hash_topic(&out)
+}
+
+/// Convert value to other type with the same ABI representation.
+/// Used by [`crate::AbiCoder`] derive to find out topic of `#[abi(with = ...)]` field, as type,
+/// returned by `encode` of `with` module, isn't known to implement [`EncodeInPlace`]
+#[doc(hidden)]
+pub fn abi_convert<F: AbiEncode, T: AbiDecode>(value: &F) -> T {
+	T::abi_decode(&value.abi_encode()).expect("types have the same ABI representation")
+}
+
+/// Topic of value type, which is encoded as a single ABI word
+pub fn word_topic<T: AbiEncode>(value: &T) -> H256 {
+	H256::from_slice(&value.abi_encode())
+}
+
+impl<T: EncodeInPlace> EncodeInPlace for &T {
+	fn encode_in_place(&self, out: &mut Vec<u8>) {
+		(*self).encode_in_place(out);
+	}
+}
+
 impl ToTopic for H256 {
 	fn to_topic(&self) -> H256 {
 		*self
 	}
 }
+impl FromTopic for H256 {
+	fn from_topic(topic: &H256) -> abi::Result<Self> {
+		Ok(*topic)
+	}
+}
+impl EncodeInPlace for H256 {
+	fn encode_in_place(&self, out: &mut Vec<u8>) {
+		out.extend_from_slice(&self.0);
+	}
+}
+
+macro_rules! impl_value_topic {
+	($($t:ty)*) => {$(
+		impl ToTopic for $t {
+			fn to_topic(&self) -> H256 {
+				word_topic(self)
+			}
+		}
+		impl FromTopic for $t {
+			fn from_topic(topic: &H256) -> abi::Result<Self> {
+				Self::abi_decode(&topic.0)
+			}
+		}
+		impl EncodeInPlace for $t {
+			fn encode_in_place(&self, out: &mut Vec<u8>) {
+				out.extend_from_slice(&self.abi_encode());
+			}
+		}
+	)*};
+}
+impl_value_topic!(bool u8 u16 u32 u64 u128 i8 i16 i32 i64 i128 U256 Address);
+
+impl<const S: usize> ToTopic for BytesFixed<S> {
+	fn to_topic(&self) -> H256 {
+		let mut out = Vec::new();
+		self.encode_in_place(&mut out);
+		if out.len() == 32 {
+			H256::from_slice(&out)
+		} else {
+			hash_topic(&out)
+		}
+	}
+}
+impl<const S: usize> FromTopic for BytesFixed<S> {
+	fn from_topic(topic: &H256) -> abi::Result<Self> {
+		if S > 32 || topic.0[S..].iter().any(|b| *b != 0) {
+			return Err(abi::Error::InvalidRange);
+		}
+		let mut out = [0; S];
+		out.copy_from_slice(&topic.0[..S]);
+		Ok(BytesFixed(out))
+	}
+}
+impl<const S: usize> EncodeInPlace for BytesFixed<S> {
+	fn encode_in_place(&self, out: &mut Vec<u8>) {
+		encode_padded(&self.0, out);
+	}
+}
+
+fn encode_padded(data: &[u8], out: &mut Vec<u8>) {
+	out.extend_from_slice(data);
+	out.resize(out.len() + (32 - data.len() % 32) % 32, 0);
+}
+
+impl ToTopic for String {
+	fn to_topic(&self) -> H256 {
+		hash_topic(self.as_bytes())
+	}
+}
+impl EncodeInPlace for String {
+	fn encode_in_place(&self, out: &mut Vec<u8>) {
+		encode_padded(self.as_bytes(), out);
+	}
+}
+
+impl ToTopic for Bytes {
+	fn to_topic(&self) -> H256 {
+		hash_topic(&self.0)
+	}
+}
+impl EncodeInPlace for Bytes {
+	fn encode_in_place(&self, out: &mut Vec<u8>) {
+		encode_padded(&self.0, out);
+	}
+}
 
-impl ToTopic for U256 {
+impl<T: EncodeInPlace> ToTopic for Vec<T> {
 	fn to_topic(&self) -> H256 {
-		let mut out = [0u8; 32];
-		self.to_big_endian(&mut out);
-		H256(out)
+		reference_topic(self)
+	}
+}
+impl<T: EncodeInPlace> EncodeInPlace for Vec<T> {
+	fn encode_in_place(&self, out: &mut Vec<u8>) {
+		for item in self {
+			item.encode_in_place(out);
+		}
 	}
 }
 
-impl ToTopic for Address {
+impl<T: EncodeInPlace, const S: usize> ToTopic for [T; S] {
 	fn to_topic(&self) -> H256 {
-		let mut out = [0u8; 32];
-		out[12..32].copy_from_slice(&self.0);
-		H256(out)
+		reference_topic(self)
+	}
+}
+impl<T: EncodeInPlace, const S: usize> EncodeInPlace for [T; S] {
+	fn encode_in_place(&self, out: &mut Vec<u8>) {
+		for item in self {
+			item.encode_in_place(out);
+		}
+	}
+}
+
+#[impl_for_tuples(0, 16)]
+impl EncodeInPlace for Tuple {
+	fn encode_in_place(&self, out: &mut Vec<u8>) {
+		for_tuples!( #( self.Tuple.encode_in_place(out); )* );
 	}
 }
 
-impl ToTopic for u32 {
+impl<T: EncodeInPlace + Default> ToTopic for Option<T> {
 	fn to_topic(&self) -> H256 {
-		let mut out = [0u8; 32];
-		out[28..32].copy_from_slice(&self.to_be_bytes());
-		H256(out)
+		reference_topic(self)
+	}
+}
+impl<T: EncodeInPlace + Default> EncodeInPlace for Option<T> {
+	fn encode_in_place(&self, out: &mut Vec<u8>) {
+		self.is_some().encode_in_place(out);
+		match self {
+			Some(value) => value.encode_in_place(out),
+			None => T::default().encode_in_place(out),
+		}
+	}
+}
+
+impl<T: EncodeInPlace> ToTopic for OptionAsArray<T> {
+	fn to_topic(&self) -> H256 {
+		reference_topic(self)
+	}
+}
+impl<T: EncodeInPlace> EncodeInPlace for OptionAsArray<T> {
+	fn encode_in_place(&self, out: &mut Vec<u8>) {
+		if let Some(value) = &self.0 {
+			value.encode_in_place(out);
+		}
+	}
+}
+
+impl<T: ToTopic + Default> ToTopic for OptionAsZero<T> {
+	fn to_topic(&self) -> H256 {
+		match &self.0 {
+			Some(value) => value.to_topic(),
+			None => T::default().to_topic(),
+		}
+	}
+}
+impl<T: EncodeInPlace + Default> EncodeInPlace for OptionAsZero<T> {
+	fn encode_in_place(&self, out: &mut Vec<u8>) {
+		match &self.0 {
+			Some(value) => value.encode_in_place(out),
+			None => T::default().encode_in_place(out),
+		}
+	}
+}
+
+impl<T: EncodeInPlace + Default> EncodeInPlace for Zero<T> {
+	fn encode_in_place(&self, out: &mut Vec<u8>) {
+		T::default().encode_in_place(out);
 	}
 }
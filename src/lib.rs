@@ -23,7 +23,9 @@ extern crate self as evm_coder;
 
 pub use evm_coder_procedural::{event_topic, fn_selector};
 pub mod abi;
-pub use events::{ToLog, ToTopic};
+pub mod client;
+pub mod descriptor;
+pub use events::{FromLog, FromTopic, ToLog, ToTopic};
 #[macro_use]
 pub mod custom_signature;
 
@@ -34,13 +36,25 @@ pub use ethereum;
 ///
 /// ## Macro syntax
 ///
-/// `#[solidity_interface(name, is, inline_is, events)]`
+/// `#[solidity_interface(name, is, inline_is, events, client, weight)]`
 /// - **`name`** - used in generated code, and for Call enum name
 /// - **`is`** - used to provide inheritance in Solidity
 /// - **`inline_is`** - same as **`is`**, but `ERC165::SupportsInterface` will work differently: For `is` SupportsInterface(A) will return true
 ///   if A is one of the interfaces the contract is inherited from (e.g. B is created as `is(A)`). If B is created as `inline_is(A)`
 ///   SupportsInterface(A) will internally create a new interface that combines all methods of A and B, so SupportsInterface(A) will return
-///   false.
+///   false. In generated solidity, methods of A are copied into B instead of inheriting A, so
+///   the interface id computed by solidity matches the one reported by SupportsInterface.
+/// - **`client`** - name of generated typed client struct, i.e `client = ERC20Client` generates
+///   `ERC20Client<T: client::Transport>`, which has a method for every interface method, encoding
+///   call, passing it to [`client::Transport`] and decoding the result. `Caller` and `&mut EventSink`
+///   arguments are omitted, and `Value` argument is transferred as call value. Methods of
+///   interfaces, inherited with `is` or `inline_is`, are available through accessors named
+///   after them (i.e `client.erc20_metadata().name()`), which are callable if inherited
+///   interface has its own `client`. Not supported for generic interfaces.
+/// - **`weight`** - type of method weights, i.e `weight = u64`. Weight of each method is specified
+///   with `#[weight(expr)]` attribute, where `expr` may refer to method arguments by reference.
+///   Generated call enum implements [`Weighted`] and has `fn weight(&self) -> Type` method, which
+///   also covers inherited interfaces. Methods without `#[weight]` attribute have default weight.
 ///
 /// `#[solidity_interface(rename_selector)]`
 /// - **`rename_selector`** - by default, selector name will be generated by transforming method name
@@ -49,6 +63,43 @@ pub use ethereum;
 /// required by ERC721 standard is `tokenURI`, thus we need to specify `rename_selector = "tokenURI"`
 /// explicitly.
 ///
+/// `#[solidity(returns(name, ...))]`
+/// - **`returns`** - returns tuple result of method (i.e `Result<(U256, bool)>`) as multiple
+///   solidity values, with specified names, `_` is used for unnamed value. Without this option
+///   tuple is returned as a single struct value. Names are used in solidity stubs and in
+///   [`descriptor::MethodDescriptor::returns`]. Named values, which have no `@return name`
+///   NatSpec in method doccomment, get it appended in solidity stubs, unnamed values preceding
+///   them should be documented with `@return` manually, as tags are matched to values by order.
+///
+/// `#[solidity(fallback)]`, `#[solidity(receive)]`
+/// - **`fallback`** - marks method, which is called for unknown selectors. It should be mutable,
+///   and may take a single `Bytes` argument with full call data, in which case it should also
+///   return `Bytes`, which are returned to caller without ABI encoding. Fallback without arguments
+///   can't return output, same as in solidity.
+/// - **`receive`** - marks method, which is called for empty call data. It should be payable,
+///   and can't take other arguments.
+///
+/// Both functions have no selectors, and are only dispatched by [`Call::parse_full`].
+///
+/// Generated call enum also has `DESCRIPTOR` constant with [`descriptor::InterfaceDescriptor`],
+/// which describes interface methods (including fallback and receive functions) and parents, and
+/// is available without `stubgen` feature.
+///
+/// Selectors of all methods of interface and its `is`/`inline_is` parents should be unique,
+/// otherwise compilation fails with error, naming both colliding methods.
+///
+/// Besides [`Callable`], macro implements [`CallableView`] for static calls, which only
+/// dispatches `&self` and pure methods. Interfaces inherited with `is(A(via(method returns T)))`
+/// are dispatched in static context only if read-only accessor is specified too, i.e
+/// `is(A(via(method_mut returns T, view = method)))`, otherwise their calls are rejected.
+///
+/// Interfaces, listed in `is`/`inline_is`, should also be declared with this macro, as it relies
+/// not only on [`Call`] and [`Callable`] implementations of parents, but on other generated items too:
+/// `SELECTOR_COUNT`, `selector_at`, `selector_name_at` and `parse_at` (used for selector collision
+/// checks and dispatch), `DESCRIPTOR`, [`Weighted`] and [`CallableView`] implementations, and
+/// `generate_solidity_interface`, `solidity_functions` and `solidity_is` with `stubgen` feature.
+/// Call enums with manually written [`Call`] implementation can't be inherited.
+///
 /// Both contract and contract methods may have doccomments, which will end up in a generated
 /// solidity interface file, thus you should use [solidity syntax](https://docs.soliditylang.org/en/latest/natspec-format.html) for writing documentation in this macro
 ///
@@ -65,7 +116,7 @@ pub use ethereum;
 /// }
 ///
 /// /// @dev This contract provides function to multiply two numbers
-/// #[solidity_interface(name = MyContract, is(SuperContract), inline_is(InlineContract))]
+/// #[solidity_interface(name = MyContract, is(SuperContract), inline_is(InlineContract), weight = u32)]
 /// impl Contract {
 ///     /// Multiply two numbers
 ///     /// @param a First number
@@ -93,11 +144,45 @@ pub use evm_coder_procedural::solidity_interface;
 /// - [`SolidityTypeName`](solidity::SolidityTypeName)
 /// - [`SolidityStructTy`](solidity::SolidityStructTy) - for struct
 /// - [`SolidityEnumTy`](solidity::SolidityEnumTy) - for enum
+/// - [`ToTopic`] - only if every field implements [`events::EncodeInPlace`], so derived type
+///   may be used as `#[indexed]` event field
 ///
 /// ### Limitations
 /// - All struct fields must implement traits listed above.
-/// - Enum must have u8 layout.
-/// - Enum must implement folowing traits: Default, Copy, Clone
+/// - Enum without fields must have `u8`, `u16` or `u32` layout, and is encoded as matching `uintN`.
+/// - Enum without fields must implement folowing traits: Default, Copy, Clone
+///
+/// ### Enum values
+/// Enums without fields may have explicitly specified option values (integer literals), which are
+/// used in encoding, decoding only accepts declared values. As solidity enums can't have gaps,
+/// and are always encoded as `uint8`, enums with values other than `0, 1, 2, ...`, or with layout
+/// other than `u8` are represented in solidity as `uintN` user-defined value type, with options declared
+/// as constants in `{Name}Lib` library.
+///
+/// ### Field attributes
+/// Struct fields may be customized with `#[abi(...)]` attribute:
+/// - **`skip`** - field is not encoded, and is initialized with `Default::default()` on decoding
+/// - **`rename = "name"`** - field name, used in generated solidity struct
+/// - **`with = path`** - field is converted to/from other type before encoding/after decoding,
+///   `path` should be a module with `type Abi`, `fn encode(&T) -> Abi` and
+///   `fn decode(Abi) -> abi::Result<T>` items. `encode` may also return a borrowing type,
+///   encoded the same way as `Abi`, to avoid copying the field
+/// - **`bytes`** - shorthand for `with = evm_coder::abi::with::bytes`, encodes `Vec<u8>` as `bytes`
+///   instead of `uint8[]`
+///
+/// ### Generic types
+/// Structs and enums with fields may have type and const generic parameters, every field type
+/// which depends on them gets required trait bounds. In solidity, such types are monomorphised,
+/// with solidity names of generic arguments appended to struct name, i.e `Page<U256>` becomes `PageUint256`.
+///
+/// ### Enums with fields
+/// Enums, which have at least one option with fields, are encoded as `(uint8 kind, ...)` tuple,
+/// where `kind` is an index of the selected option, and every option with fields adds one
+/// component to this tuple: field type for options with single field, and tuple of field types otherwise.
+/// Components of not selected options are zeroed, thus their types should implement `Default` and `PartialEq`.
+///
+/// In solidity, such enum is represented as struct with the same layout, and `kind` field of
+/// generated `{Name}Kind` enum type.
 ///
 /// ### Example
 /// ```
@@ -109,6 +194,16 @@ pub use evm_coder_procedural::solidity_interface;
 ///     b: String
 /// }
 ///
+/// #[derive(AbiCoder)]
+/// struct Bar {
+///     #[abi(rename = "id")]
+///     bar_id: u32,
+///     #[abi(bytes)]
+///     data: Vec<u8>,
+///     #[abi(skip)]
+///     cache: Option<u32>,
+/// }
+///
 /// #[derive(AbiCoder, Default, Clone, Copy)]
 /// #[repr(u8)]
 /// enum Color {
@@ -117,24 +212,60 @@ pub use evm_coder_procedural::solidity_interface;
 ///     #[default]
 ///     Blue,
 /// }
+///
+/// // Encoded as (uint8,address,uint32,(uint32,uint32))
+/// #[derive(AbiCoder)]
+/// enum Target {
+///     Account(evm_coder::types::Address),
+///     Collection(u32),
+///     Token(u32, u32),
+/// }
 /// ```
 pub use evm_coder_procedural::AbiCoder;
 #[cfg(feature = "bondrewd")]
 pub use evm_coder_procedural::AbiCoderFlags;
-/// Derives [`ToLog`] for enum
+/// Derives [`FromLog`] for enum or struct, which should also derive [`ToLog`]
+///
+/// Log is matched against event selectors generated by [`ToLog`] derive, `#[indexed]` fields
+/// are restored from topics using [`FromTopic`], and other fields are decoded from log data.
+/// Logs of other events are skipped with `Ok(None)`.
+pub use evm_coder_procedural::FromLog;
+/// Derives [`ToLog`] for enum, where every variant is an event, or for struct with named fields,
+/// which is a single event named after the struct
 ///
-/// Selectors will be derived from variant names, there is currently no way to have custom naming
-/// for them
+/// Selectors will be derived from variant names, and solidity names of fields are camelCased
+/// field names. Doc comments of variants are written to solidity stubs as event docs, and doc
+/// comments of fields are written as NatSpec `@param` descriptions.
+///
+/// For every event, a typed topic filter is generated, i.e for `Events::Transfer` variant,
+/// `Events::transfer_filter()` returns `EventsTransferFilter` (for `Transfer` struct,
+/// `Transfer::filter()` returns `TransferFilter`), which has a setter for every
+/// `#[indexed]` field, and `matches(&Log)` method. Fields, which are not set, match any value.
+/// Filter can also be checked against [`events::LogsBloom`] of block or receipt with
+/// `might_match(&LogsBloom)`, to skip blocks, which certainly have no matching logs.
+/// Because of that, indexed fields can't be named `topics`, `matches` or `might_match`.
 ///
 /// `#[indexed]`
-/// Marks this field as indexed, so it will appear in [`ethereum::Log`] topics instead of data
+/// Marks this field as indexed, so it will appear in [`ethereum::Log`] topics instead of data.
+/// Field type should implement [`ToTopic`], reference types (strings, bytes, arrays and structs)
+/// are stored in topics as keccak256 hash of their encoding, same way solidity does it
+///
+/// `#[anonymous]`
+/// Marks this variant as anonymous event, its selector is not included in topics,
+/// thus it can have up to 4 indexed fields. [`FromLog::from_log`] can't match anonymous events by selector,
+/// and ignores them, [`FromLog::from_log_anonymous`] should be used instead, it returns
+/// the first anonymous event, which can be decoded from log
+///
+/// `#[event(rename = "...")]`
+/// On variant: sets solidity event name, which is used in selector and stubs.
+/// On field: sets solidity field name, instead of camelCased one
 pub use evm_coder_procedural::ToLog;
 /// Reexported for macro
 #[doc(hidden)]
 pub use sha3_const;
 
 pub use self::abi::{AbiDecode, AbiDecoder, AbiEncode, AbiEncoder};
-use self::{abi::Error, types::*};
+use self::{abi::Error, custom_signature::SignatureUnit, types::*};
 
 // Api of those modules shouldn't be consumed directly, it is only exported for usage in proc macros
 #[doc(hidden)]
@@ -193,11 +324,47 @@ pub mod types {
 		NonZero(T),
 	}
 
+	/// Optional value, represented in ABI as `T[]` array with zero or one element.
+	///
+	/// Unlike plain `Option<T>`, which is encoded as `(bool, T)` tuple, this representation
+	/// doesn't require a separate struct definition in solidity.
+	/// Arrays of more than one element are rejected on decoding.
+	#[derive(Default, Debug, PartialEq, Eq, Clone)]
+	pub struct OptionAsArray<T>(pub Option<T>);
+
+	/// Optional value, represented in ABI as plain `T`, where zero (default) value
+	/// is used as a sentinel for `None`.
+	///
+	/// Note that `Some(T::default())` is indistinguishable from `None` in this representation,
+	/// and will be decoded as `None`.
+	/// This representation is also suitable for contracts, which expose value presence via
+	/// separate getter.
+	#[derive(Default, Debug, PartialEq, Eq, Clone)]
+	pub struct OptionAsZero<T>(pub Option<T>);
+
+	macro_rules! impl_option_wrapper {
+		($($ty:ident)*) => {$(
+			impl<T> From<Option<T>> for $ty<T> {
+				fn from(value: Option<T>) -> Self {
+					Self(value)
+				}
+			}
+			impl<T> From<$ty<T>> for Option<T> {
+				fn from(value: $ty<T>) -> Self {
+					value.0
+				}
+			}
+		)*};
+	}
+	impl_option_wrapper!(OptionAsArray OptionAsZero);
+
 	//#region Special types
 	/// Makes function payable
 	pub type Value = U256;
 	/// Makes function caller-sensitive
 	pub type Caller = Address;
+	/// Allows function to emit events, should be passed as `&mut EventSink`
+	pub use crate::events::EventSink;
 	//#endregion
 
 	/// Ethereum typed call message, similar to solidity
@@ -207,8 +374,8 @@ pub mod types {
 		/// Address of user, which called this contract.
 		pub caller: H160,
 		/// Payment amount to contract.
-		/// Contract should reject payment, if target call is not payable,
-		/// and there is no `receiver()` function defined.
+		/// Payment is rejected by [`crate::solidity_interface`] dispatcher, if target call is
+		/// not payable, see [`crate::Contract::REJECT_NON_PAYABLE_VALUE`].
 		pub value: U256,
 	}
 
@@ -259,14 +426,52 @@ pub trait Call: Sized {
 	///
 	/// One of call arguments has bad encoding, or value is invalid for the target type
 	fn parse(selector: Bytes4, input: &[u8]) -> abi::Result<Option<Self>>;
+	/// Call of `#[solidity(fallback)]` function, which handles calls with unknown selectors
+	fn parse_fallback(_input: &[u8]) -> Option<Self> {
+		None
+	}
+	/// Call of `#[solidity(receive)]` function, which handles calls with empty call data
+	fn parse_receive() -> Option<Self> {
+		None
+	}
+	/// Parse full call data, including selector
+	///
+	/// Empty call data is dispatched to `receive` function, and calls with unknown selectors
+	/// to `fallback` function, same way solidity does it
 	fn parse_full(input: &[u8]) -> abi::Result<Option<Self>> {
+		if input.is_empty() {
+			if let Some(call) = Self::parse_receive() {
+				return Ok(Some(call));
+			}
+		}
 		if input.len() < 4 {
-			return Err(Error::OutOfOffset);
+			return Self::parse_fallback(input)
+				.map(Some)
+				.ok_or(Error::OutOfOffset);
 		}
 		let mut selector = [0; 4];
 		selector.copy_from_slice(&input[..4]);
 
-		Self::parse(BytesFixed(selector), &input[4..])
+		match Self::parse(BytesFixed(selector), &input[4..])? {
+			Some(call) => Ok(Some(call)),
+			None => Ok(Self::parse_fallback(input)),
+		}
+	}
+}
+
+/// Return value of `#[solidity(fallback)]` function, which is returned as is, without ABI encoding
+pub trait FallbackOutput {
+	/// Raw call output
+	fn into_output(self) -> Vec<u8>;
+}
+impl FallbackOutput for () {
+	fn into_output(self) -> Vec<u8> {
+		Vec::new()
+	}
+}
+impl FallbackOutput for Bytes {
+	fn into_output(self) -> Vec<u8> {
+		self.0
 	}
 }
 
@@ -274,7 +479,46 @@ pub trait Call: Sized {
 /// on interface implementation, or for externally-owned real EVM contract
 pub trait Callable<C: Call>: Contract {
 	/// Call contract using specified call data
+	///
+	/// # Events are discarded
+	///
+	/// Events, emitted by called method, are silently dropped, and can't be observed by caller.
+	/// If call is executed as a part of transaction, which should produce logs,
+	/// use [`Callable::call_with_events`] instead
 	fn call(&mut self, call: types::Msg<C>) -> ResultWithPostInfoOf<Self, Vec<u8>>;
+
+	/// Call contract using specified call data, collecting emitted events into `events`
+	///
+	/// By default, no events are emitted, this method is overridden by [`solidity_interface`]
+	/// implementation. If call returns error, events emitted by it are removed from `events`
+	fn call_with_events(
+		&mut self,
+		call: types::Msg<C>,
+		events: &mut types::EventSink,
+	) -> ResultWithPostInfoOf<Self, Vec<u8>> {
+		let _ = events;
+		self.call(call)
+	}
+}
+
+/// Type callable with ethereum message in static context (i.e `STATICCALL`), where contract
+/// state can't be modified, implemented by [`solidity_interface`] macro
+///
+/// Only `&self` (view) and pure methods may be called this way, calls to mutable methods are
+/// rejected with [`STATIC_CALL_ERROR`]
+pub trait CallableView<C: Call>: Callable<C> {
+	/// Call contract using specified call data, without mutable access to contract
+	fn call_view(&self, call: types::Msg<C>) -> ResultWithPostInfoOf<Self, Vec<u8>>;
+}
+
+/// Call, which weight can be calculated before dispatch, implemented by [`solidity_interface`]
+/// macro, using `#[weight(...)]` method attributes
+///
+/// Interfaces without `weight = Type` option implement it for any `W: Default`, delegating to
+/// their parents, thus they may be inherited by interfaces with any weight type
+pub trait Weighted<W> {
+	/// Weight of this call, calculated from its arguments
+	fn weight(&self) -> W;
 }
 
 /// Contract specific result type
@@ -294,6 +538,12 @@ pub trait Contract {
 	/// Return value of [`Callable`], expected to be of [`core::result::Result`] type
 	type Result<T, E>;
 
+	/// Reject calls with non-zero value to methods, which have no `Value` argument,
+	/// with [`NON_PAYABLE_ERROR`].
+	///
+	/// Set to `false` if payment checks are implemented by contract methods themselves
+	const REJECT_NON_PAYABLE_VALUE: bool = true;
+
 	/// Map `WithPostInfo` value
 	fn map_post<I, O>(
 		v: Self::WithPostInfo<I>,
@@ -303,6 +553,12 @@ pub trait Contract {
 	fn with_default_post<T>(v: T) -> Self::WithPostInfo<T>;
 }
 
+/// Error of call with non-zero value to non-payable method, same as solidity one
+pub const NON_PAYABLE_ERROR: &str = "Ether sent to non-payable function";
+
+/// Error of mutable method call in static context, see [`CallableView`]
+pub const STATIC_CALL_ERROR: &str = "Mutable method called in static context";
+
 /// Example of `PostInfo`, used in tests
 pub struct DummyPost<T>(pub T);
 /// Implement dummy Contract trait, used for tests
@@ -386,6 +642,54 @@ impl Call for ERC165Call {
 	}
 }
 
+/// Sort flattened selector table of interface, generated by [`solidity_interface`].
+/// Sort is stable, so first entry with duplicated selector stays first
+#[doc(hidden)]
+#[must_use]
+pub const fn sort_selector_table<const N: usize>(
+	mut table: [(u32, usize); N],
+) -> [(u32, usize); N] {
+	let mut i = 1;
+	while i < N {
+		let mut j = i;
+		while j > 0 && table[j - 1].0 > table[j].0 {
+			let v = table[j - 1];
+			table[j - 1] = table[j];
+			table[j] = v;
+			j -= 1;
+		}
+		i += 1;
+	}
+	table
+}
+
+/// Find index of method in flattened interface, using table sorted by [`sort_selector_table`]
+#[doc(hidden)]
+#[must_use]
+pub fn find_selector(table: &[(u32, usize)], selector: u32) -> Option<usize> {
+	let pos = table.partition_point(|(s, _)| *s < selector);
+	match table.get(pos) {
+		Some((s, i)) if *s == selector => Some(*i),
+		_ => None,
+	}
+}
+
+/// Fail compilation with error, naming both methods with colliding selector,
+/// used by [`solidity_interface`] selector collision check
+#[doc(hidden)]
+pub const fn selector_collision(a: &SignatureUnit, b: &SignatureUnit) -> ! {
+	const PREFIX: &str = "selector collision between ";
+	const SEPARATOR: &str = " and ";
+	if PREFIX.len() + a.len + SEPARATOR.len() + b.len > custom_signature::SIGNATURE_SIZE_LIMIT {
+		panic!("selector collision, method names are too long to be displayed");
+	}
+	let message = make_signature!(new fixed(PREFIX) nameof(a) fixed(SEPARATOR) nameof(b));
+	match core::str::from_utf8(message.data.split_at(message.len).0) {
+		Ok(message) => panic!("{}", message),
+		Err(_) => panic!("selector collision"),
+	}
+}
+
 /// Generate "tests", which will generate solidity code on execution and print it to stdout
 /// Script at `.maintain/scripts/generate_api.sh` can split this output from test runtime
 ///
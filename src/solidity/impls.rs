@@ -182,14 +182,7 @@ impl<T: SolidityTypeName> super::SolidityStructTy for Option<T> {
 		let mut solidity_name = "Option".to_string();
 		let mut generic_name = String::new();
 		T::solidity_name(&mut generic_name, tc).unwrap();
-		solidity_name.push(
-			generic_name
-				.chars()
-				.next()
-				.expect("Generic name is empty")
-				.to_ascii_uppercase(),
-		);
-		solidity_name.push_str(&generic_name[1..]);
+		super::push_generic_name(&mut solidity_name, &generic_name);
 
 		let interface = super::SolidityStruct {
 			docs: &[" Optional value"],
@@ -215,3 +208,27 @@ impl<T: SolidityTypeName> super::SolidityStructTy for Option<T> {
 		solidity_name.to_string()
 	}
 }
+
+impl<T: SolidityTypeName> SolidityTypeName for OptionAsArray<T> {
+	fn solidity_name(writer: &mut impl fmt::Write, tc: &TypeCollector) -> fmt::Result {
+		<Vec<T>>::solidity_name(writer, tc)
+	}
+	fn is_simple() -> bool {
+		<Vec<T>>::is_simple()
+	}
+	fn solidity_default(writer: &mut impl fmt::Write, tc: &TypeCollector) -> fmt::Result {
+		<Vec<T>>::solidity_default(writer, tc)
+	}
+}
+
+impl<T: SolidityTypeName> SolidityTypeName for OptionAsZero<T> {
+	fn solidity_name(writer: &mut impl fmt::Write, tc: &TypeCollector) -> fmt::Result {
+		T::solidity_name(writer, tc)
+	}
+	fn is_simple() -> bool {
+		T::is_simple()
+	}
+	fn solidity_default(writer: &mut impl fmt::Write, tc: &TypeCollector) -> fmt::Result {
+		T::solidity_default(writer, tc)
+	}
+}
@@ -76,6 +76,22 @@ impl TypeCollector {
 		data.into_iter().map(|(code, _)| code).collect()
 	}
 }
+/// Appends name of generic argument to the name of monomorphised struct,
+/// i.e `uint256` => `Uint256`, `uint8[]` => `Uint8Array`
+pub fn push_generic_name(out: &mut String, generic_name: &str) {
+	let mut chars = generic_name.chars();
+	if let Some(first) = chars.next() {
+		out.push(first.to_ascii_uppercase());
+	}
+	for c in chars {
+		match c {
+			'[' => out.push_str("Array"),
+			c if c.is_ascii_alphanumeric() => out.push(c),
+			_ => {}
+		}
+	}
+}
+
 pub struct UnnamedArgument<T>(PhantomData<*const T>);
 impl<T> Default for UnnamedArgument<T> {
 	fn default() -> Self {
@@ -329,6 +345,74 @@ impl<A: SolidityArguments, R: SolidityArguments> SolidityFunctions for SolidityF
 	}
 }
 
+/// `fallback` function, called for unknown selectors
+pub struct SolidityFallback {
+	pub docs: &'static [&'static str],
+	pub is_payable: bool,
+	/// Fallback function takes call data, and returns raw output
+	pub has_input: bool,
+}
+impl SolidityFunctions for SolidityFallback {
+	fn solidity_name(
+		&self,
+		is_impl: bool,
+		writer: &mut impl fmt::Write,
+		_tc: &TypeCollector,
+	) -> fmt::Result {
+		for doc in self.docs {
+			writeln!(writer, "\t///{doc}")?;
+		}
+		if self.has_input {
+			write!(writer, "\tfallback(bytes calldata input) external")?;
+		} else {
+			write!(writer, "\tfallback() external")?;
+		}
+		if self.is_payable {
+			write!(writer, " payable")?;
+		}
+		if self.has_input {
+			write!(writer, " returns (bytes memory)")?;
+		}
+		if is_impl {
+			writeln!(writer, " {{")?;
+			writeln!(writer, "\t\trequire(false, stub_error);")?;
+			writeln!(writer, "\t\tdummy = 0;")?;
+			if self.has_input {
+				writeln!(writer, "\t\treturn input;")?;
+			}
+			writeln!(writer, "\t}}")
+		} else {
+			writeln!(writer, ";")
+		}
+	}
+}
+
+/// `receive` function, called for calls with empty call data
+pub struct SolidityReceive {
+	pub docs: &'static [&'static str],
+}
+impl SolidityFunctions for SolidityReceive {
+	fn solidity_name(
+		&self,
+		is_impl: bool,
+		writer: &mut impl fmt::Write,
+		_tc: &TypeCollector,
+	) -> fmt::Result {
+		for doc in self.docs {
+			writeln!(writer, "\t///{doc}")?;
+		}
+		write!(writer, "\treceive() external payable")?;
+		if is_impl {
+			writeln!(writer, " {{")?;
+			writeln!(writer, "\t\trequire(false, stub_error);")?;
+			writeln!(writer, "\t\tdummy = 0;")?;
+			writeln!(writer, "\t}}")
+		} else {
+			writeln!(writer, ";")
+		}
+	}
+}
+
 #[impl_for_tuples(0, 48)]
 impl SolidityFunctions for Tuple {
 	for_tuples!( where #( Tuple: SolidityFunctions ),* );
@@ -361,6 +445,18 @@ impl<F: SolidityFunctions> SolidityInterface<F> {
 		is_impl: bool,
 		out: &mut impl fmt::Write,
 		tc: &TypeCollector,
+	) -> fmt::Result {
+		self.format_inlined(&[], is_impl, out, tc)
+	}
+
+	/// Same as [`Self::format`], but interface also inherits parents of `inline_is` interfaces,
+	/// functions of which should be included into `functions`
+	pub fn format_inlined(
+		&self,
+		inline_is: &[&str],
+		is_impl: bool,
+		out: &mut impl fmt::Write,
+		tc: &TypeCollector,
 	) -> fmt::Result {
 		const ZERO_BYTES: [u8; 4] = [0; 4];
 		for doc in self.docs {
@@ -379,9 +475,10 @@ impl<F: SolidityFunctions> SolidityInterface<F> {
 			write!(out, "interface ")?;
 		}
 		write!(out, "{}", self.name)?;
-		if !self.is.is_empty() {
+		let inline_is = inline_is.iter().filter(|n| !self.is.contains(n));
+		if !self.is.is_empty() || inline_is.clone().next().is_some() {
 			write!(out, " is")?;
-			for (i, n) in self.is.iter().enumerate() {
+			for (i, n) in self.is.iter().chain(inline_is).enumerate() {
 				if i != 0 {
 					write!(out, ",")?;
 				}
@@ -396,8 +493,11 @@ impl<F: SolidityFunctions> SolidityInterface<F> {
 }
 
 pub struct SolidityEvent<A> {
+	pub docs: &'static [&'static str],
 	pub name: &'static str,
 	pub args: A,
+	/// Anonymous events have no signature topic
+	pub anonymous: bool,
 }
 
 impl<A: SolidityArguments> SolidityFunctions for SolidityEvent<A> {
@@ -407,9 +507,16 @@ impl<A: SolidityArguments> SolidityFunctions for SolidityEvent<A> {
 		writer: &mut impl fmt::Write,
 		tc: &TypeCollector,
 	) -> fmt::Result {
+		for doc in self.docs {
+			writeln!(writer, "\t///{doc}")?;
+		}
 		write!(writer, "\tevent {}(", self.name)?;
 		self.args.solidity_name(writer, tc)?;
-		writeln!(writer, ");")
+		if self.anonymous {
+			writeln!(writer, ") anonymous;")
+		} else {
+			writeln!(writer, ");")
+		}
 	}
 }
 
@@ -446,6 +553,22 @@ where
 		Ok(())
 	}
 }
+/// Struct field, which type has no Rust counterpart, and is referenced by name,
+/// i.e kind of derived enum with fields
+pub struct SolidityStructRawField {
+	pub docs: &'static [&'static str],
+	pub name: &'static str,
+	pub ty: &'static str,
+}
+
+impl SolidityItems for SolidityStructRawField {
+	fn solidity_name(&self, out: &mut impl fmt::Write, _tc: &TypeCollector) -> fmt::Result {
+		for doc in self.docs {
+			writeln!(out, "///{doc}")?;
+		}
+		writeln!(out, "\t{} {};", self.ty, self.name)
+	}
+}
 pub struct SolidityStruct<'a, F> {
 	pub docs: &'a [&'a str],
 	// pub generics:
@@ -505,6 +628,46 @@ impl SolidityEnum {
 	}
 }
 
+pub struct SolidityEnumConstant {
+	pub docs: &'static [&'static str],
+	pub name: &'static str,
+	pub value: u32,
+}
+/// Enum with explicitly specified values, which can't be represented as solidity enum,
+/// as solidity enum options are always numbered sequentially
+pub struct SolidityEnumLibrary {
+	pub docs: &'static [&'static str],
+	pub name: &'static str,
+	pub ty: &'static str,
+	pub fields: &'static [SolidityEnumConstant],
+}
+impl SolidityEnumLibrary {
+	pub fn format(&self, out: &mut impl fmt::Write) -> fmt::Result {
+		for doc in self.docs {
+			writeln!(out, "///{doc}")?;
+		}
+		let name = self.name;
+		let ty = self.ty;
+		writeln!(out, "type {name} is {ty};")?;
+		write!(out, "library {name}Lib {{")?;
+		for field in self.fields {
+			writeln!(out)?;
+			for doc in field.docs {
+				writeln!(out, "///{doc}")?;
+			}
+			let field_name = field.name;
+			let value = field.value;
+			write!(
+				out,
+				"\t{name} constant {field_name} = {name}.wrap({value});"
+			)?;
+		}
+		writeln!(out)?;
+		writeln!(out, "}}")?;
+		Ok(())
+	}
+}
+
 pub enum SolidityFlagsField {
 	Bool(SolidityFlagsBool),
 	Number(SolidityFlagsNumber),
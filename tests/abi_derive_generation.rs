@@ -1,3 +1,11 @@
+/// Solidity definitions, generated for type and types it depends on
+#[cfg(feature = "stubgen")]
+fn solidity_output<T: evm_coder::solidity::SolidityTypeName>() -> String {
+	let tc = evm_coder::solidity::TypeCollector::new();
+	T::solidity_name(&mut String::new(), &tc).unwrap();
+	tc.finish().join("")
+}
+
 mod test_struct {
 	use evm_coder::types::{Bytes, Bytes4, BytesFixed};
 	use evm_coder_procedural::AbiCoder;
@@ -707,6 +715,487 @@ mod test_enum {
 			assert_eq!(restored_enum_data, Color::Green);
 		}
 	}
+
+	#[derive(AbiCoder, Debug, PartialEq, Default, Clone, Copy)]
+	#[repr(u8)]
+	enum Status {
+		#[default]
+		Active = 1,
+		Frozen = 5,
+		Closed,
+	}
+
+	#[test]
+	fn explicit_values() {
+		assert_eq!(Status::Frozen.abi_encode(), 5u8.abi_encode());
+		assert_eq!(Status::Closed.abi_encode(), 6u8.abi_encode());
+		assert_eq!(
+			Status::abi_decode(&6u8.abi_encode()).unwrap(),
+			Status::Closed
+		);
+		assert!(Status::abi_decode(&0u8.abi_encode()).is_err());
+		assert!(Status::abi_decode(&2u8.abi_encode()).is_err());
+	}
+
+	#[derive(AbiCoder, Debug, PartialEq, Default, Clone, Copy)]
+	#[repr(u16)]
+	enum Permission {
+		#[default]
+		Read,
+		Write,
+		Admin = 300,
+	}
+
+	#[derive(AbiCoder, Debug, PartialEq, Default, Clone, Copy)]
+	#[repr(u32)]
+	enum ErrorCode {
+		#[default]
+		Ok,
+		Fatal = 100_000,
+	}
+
+	#[cfg(feature = "stubgen")]
+	#[test]
+	fn solidity_definition() {
+		// Sparse values can't be represented with solidity enum
+		let out = super::solidity_output::<Status>();
+		assert_eq!(
+			out,
+			"type Status is uint8;\nlibrary StatusLib {\n\tStatus constant Active = Status.wrap(1);\n\tStatus constant Frozen = Status.wrap(5);\n\tStatus constant Closed = Status.wrap(6);\n}\n"
+		);
+		// Solidity enums are always uint8
+		let out = super::solidity_output::<Permission>();
+		assert_eq!(
+			out,
+			"type Permission is uint16;\nlibrary PermissionLib {\n\tPermission constant Read = Permission.wrap(0);\n\tPermission constant Write = Permission.wrap(1);\n\tPermission constant Admin = Permission.wrap(300);\n}\n"
+		);
+		assert!(super::solidity_output::<Color>().contains("enum Color {"));
+	}
+
+	#[test]
+	fn wide_repr() {
+		assert_eq!(
+			<Permission as evm_coder::abi::AbiType>::SIGNATURE
+				.as_str()
+				.unwrap(),
+			"uint16"
+		);
+		assert_eq!(
+			<ErrorCode as evm_coder::abi::AbiType>::SIGNATURE
+				.as_str()
+				.unwrap(),
+			"uint32"
+		);
+		assert_eq!(Permission::Admin.abi_encode(), 300u16.abi_encode());
+		assert_eq!(Permission::try_from(1u16), Ok(Permission::Write));
+		assert_eq!(
+			ErrorCode::abi_decode(&100_000u32.abi_encode()).unwrap(),
+			ErrorCode::Fatal
+		);
+		assert!(ErrorCode::abi_decode(&1u32.abi_encode()).is_err());
+	}
+}
+
+mod test_tagged_enum {
+	use evm_coder::{
+		types::{Address, Bytes4, BytesFixed},
+		AbiCoder, AbiDecode, AbiEncode,
+	};
+
+	/// Target of operation
+	#[derive(AbiCoder, Debug, PartialEq)]
+	enum Target {
+		/// Some account
+		Account(Address),
+		Collection(u32),
+		Token(u32, u32),
+		Named {
+			name: String,
+			id: u32,
+		},
+		Nothing,
+	}
+
+	const FUNCTION_IDENTIFIER: Bytes4 = BytesFixed(u32::to_be_bytes(0xdeadbeef));
+
+	#[test]
+	fn impl_abi_type_signature() {
+		assert_eq!(
+			<Target as evm_coder::abi::AbiType>::SIGNATURE
+				.as_str()
+				.unwrap(),
+			"(uint8,address,uint32,(uint32,uint32),(string,uint32))"
+		);
+	}
+
+	#[cfg(feature = "stubgen")]
+	#[test]
+	fn solidity_definition() {
+		let out = super::solidity_output::<Target>();
+		assert!(
+			out.contains(
+				"/// Kind of `Target` value\nenum TargetKind {\n/// Some account\n\tAccount,\n\tCollection,\n\tToken,\n\tNamed,\n\tNothing\n}\n"
+			),
+			"{out}"
+		);
+		assert!(
+			out.contains("/// Target of operation\nstruct Target {\n/// Selected option\n\tTargetKind kind;\n"),
+			"{out}"
+		);
+		for field in [
+			"\taddress account;",
+			"\tuint32 collection;",
+			"\tTuple1 token;",
+			"\tTuple3 named;",
+		] {
+			assert!(out.contains(field), "{out}");
+		}
+		assert!(!out.contains("nothing;"), "{out}");
+	}
+
+	fn test_impl<T>(value: Target, tuple: T)
+	where
+		T: AbiEncode + AbiDecode + PartialEq + std::fmt::Debug,
+	{
+		let encoded_enum = value.abi_encode_call(FUNCTION_IDENTIFIER);
+		let encoded_tuple = tuple.abi_encode_call(FUNCTION_IDENTIFIER);
+		similar_asserts::assert_eq!(encoded_enum, encoded_tuple);
+
+		let (_, restored) = Target::abi_decode_call(&encoded_tuple).unwrap();
+		assert_eq!(restored, value);
+	}
+
+	#[test]
+	fn codec_unnamed() {
+		test_impl(
+			Target::Token(1, 2),
+			(
+				2u8,
+				Address::zero(),
+				0u32,
+				(1u32, 2u32),
+				(String::new(), 0u32),
+			),
+		);
+	}
+
+	#[test]
+	fn codec_named() {
+		test_impl(
+			Target::Named {
+				name: "name".into(),
+				id: 5,
+			},
+			(
+				3u8,
+				Address::zero(),
+				0u32,
+				(0u32, 0u32),
+				("name".to_string(), 5u32),
+			),
+		);
+	}
+
+	#[test]
+	fn codec_unit() {
+		test_impl(
+			Target::Nothing,
+			(
+				4u8,
+				Address::zero(),
+				0u32,
+				(0u32, 0u32),
+				(String::new(), 0u32),
+			),
+		);
+	}
+
+	#[test]
+	fn decode_rejects_bad_data() {
+		let unknown_kind = (
+			5u8,
+			Address::zero(),
+			0u32,
+			(0u32, 0u32),
+			(String::new(), 0u32),
+		)
+			.abi_encode();
+		assert!(Target::abi_decode(&unknown_kind).is_err());
+
+		let inactive_not_zero = (
+			1u8,
+			Address::repeat_byte(1),
+			0u32,
+			(0u32, 0u32),
+			(String::new(), 0u32),
+		)
+			.abi_encode();
+		assert!(Target::abi_decode(&inactive_not_zero).is_err());
+	}
+}
+
+mod test_field_attributes {
+	use evm_coder::{
+		types::{Address, Bytes},
+		AbiCoder, AbiDecode, AbiEncode,
+	};
+
+	mod address_as_u32 {
+		use evm_coder::{abi::Result, types::Address};
+
+		pub type Abi = u32;
+		pub fn encode(value: &Address) -> Abi {
+			u32::from_be_bytes(value.0[16..].try_into().unwrap())
+		}
+		pub fn decode(value: Abi) -> Result<Address> {
+			Ok(Address::from_low_u64_be(value.into()))
+		}
+	}
+
+	#[derive(AbiCoder, Debug, PartialEq)]
+	struct Named {
+		a: u8,
+		#[abi(skip)]
+		skipped: Option<u32>,
+		#[abi(rename = "renamed")]
+		b: u32,
+		#[abi(bytes)]
+		data: Vec<u8>,
+		#[abi(with = address_as_u32)]
+		owner: Address,
+	}
+
+	#[derive(AbiCoder, Debug, PartialEq)]
+	struct Unnamed(#[abi(skip)] bool, #[abi(bytes)] Vec<u8>, u32);
+
+	#[cfg(feature = "stubgen")]
+	#[test]
+	fn solidity_definition() {
+		let out = super::solidity_output::<Named>();
+		assert!(
+			out.contains(
+				"struct Named {\n\tuint8 a;\n\tuint32 renamed;\n\tbytes data;\n\tuint32 owner;\n}"
+			),
+			"{out}"
+		);
+		let out = super::solidity_output::<Unnamed>();
+		assert!(
+			out.contains("struct Unnamed {\n\tbytes field_1;\n\tuint32 field_2;\n}"),
+			"{out}"
+		);
+	}
+
+	#[test]
+	fn impl_abi_type_signature() {
+		assert_eq!(
+			<Named as evm_coder::abi::AbiType>::SIGNATURE
+				.as_str()
+				.unwrap(),
+			"(uint8,uint32,bytes,uint32)"
+		);
+		assert_eq!(
+			<Unnamed as evm_coder::abi::AbiType>::SIGNATURE
+				.as_str()
+				.unwrap(),
+			"(bytes,uint32)"
+		);
+	}
+
+	#[test]
+	fn codec_named() {
+		let value = Named {
+			a: 1,
+			skipped: Some(5),
+			b: 2,
+			data: vec![1, 2, 3],
+			owner: Address::from_low_u64_be(4),
+		};
+		let encoded = value.abi_encode();
+		similar_asserts::assert_eq!(
+			encoded,
+			(1u8, 2u32, Bytes(vec![1, 2, 3]), 4u32).abi_encode()
+		);
+		let restored = Named::abi_decode(&encoded).unwrap();
+		assert_eq!(
+			restored,
+			Named {
+				skipped: None,
+				..value
+			}
+		);
+	}
+
+	#[test]
+	fn codec_unnamed() {
+		let value = Unnamed(true, vec![1, 2, 3], 4);
+		let encoded = value.abi_encode();
+		similar_asserts::assert_eq!(encoded, (Bytes(vec![1, 2, 3]), 4u32).abi_encode());
+		let restored = Unnamed::abi_decode(&encoded).unwrap();
+		assert_eq!(restored, Unnamed(false, vec![1, 2, 3], 4));
+	}
+}
+
+mod test_generics {
+	#[cfg(feature = "stubgen")]
+	use evm_coder::types::BytesFixed;
+	use evm_coder::{types::Address, AbiCoder, AbiDecode, AbiEncode};
+	use primitive_types::U256;
+
+	#[derive(AbiCoder, Debug, PartialEq)]
+	struct Page<T> {
+		items: Vec<T>,
+		next: Option<U256>,
+	}
+
+	#[derive(AbiCoder, Debug, PartialEq)]
+	struct Fixed<const N: usize>([u32; N], u8);
+
+	#[cfg(feature = "stubgen")]
+	#[derive(AbiCoder, Debug, PartialEq)]
+	struct Tagged<const N: usize> {
+		tag: BytesFixed<N>,
+	}
+
+	#[derive(AbiCoder, Debug, PartialEq)]
+	enum Either<L, R> {
+		Left(L),
+		Right(R),
+	}
+
+	#[test]
+	fn impl_abi_type_signature() {
+		assert_eq!(
+			<Page<U256> as evm_coder::abi::AbiType>::SIGNATURE
+				.as_str()
+				.unwrap(),
+			"(uint256[],(bool,uint256))"
+		);
+		assert_eq!(
+			<Fixed<4> as evm_coder::abi::AbiType>::SIGNATURE
+				.as_str()
+				.unwrap(),
+			"(uint32[4],uint8)"
+		);
+		assert_eq!(
+			<Either<u32, Address> as evm_coder::abi::AbiType>::SIGNATURE
+				.as_str()
+				.unwrap(),
+			"(uint8,uint32,address)"
+		);
+	}
+
+	#[test]
+	fn codec() {
+		let page = Page {
+			items: vec![Address::repeat_byte(1)],
+			next: Some(U256::from(2)),
+		};
+		let encoded = page.abi_encode();
+		similar_asserts::assert_eq!(
+			encoded,
+			(vec![Address::repeat_byte(1)], (true, U256::from(2))).abi_encode()
+		);
+		assert_eq!(Page::<Address>::abi_decode(&encoded).unwrap(), page);
+
+		let fixed = Fixed([1, 2], 3);
+		assert_eq!(Fixed::<2>::abi_decode(&fixed.abi_encode()).unwrap(), fixed);
+
+		let either = Either::<u32, String>::Right("right".into());
+		assert_eq!(
+			Either::<u32, String>::abi_decode(&either.abi_encode()).unwrap(),
+			either
+		);
+	}
+
+	#[cfg(feature = "stubgen")]
+	#[test]
+	fn solidity_name() {
+		use evm_coder::solidity::{SolidityTypeName, TypeCollector};
+
+		fn name<T: SolidityTypeName>() -> String {
+			let mut out = String::new();
+			T::solidity_name(&mut out, &TypeCollector::new()).unwrap();
+			out
+		}
+		assert_eq!(name::<Page<U256>>(), "PageUint256");
+		assert_eq!(name::<Page<Vec<u8>>>(), "PageUint8Array");
+		assert_eq!(name::<Tagged<4>>(), "Tagged4");
+		assert_eq!(name::<Either<u32, Address>>(), "EitherUint32Address");
+	}
+}
+
+mod test_custom_field {
+	use evm_coder::{
+		abi::{AbiDecoder, AbiEncoder, AbiType, Result},
+		custom_signature::SignatureUnit,
+		AbiCoder, AbiDecode, AbiEncode,
+	};
+
+	/// Implements only ABI traits, and not topic ones
+	#[derive(Default, Debug, PartialEq)]
+	struct Custom(u32);
+	impl AbiType for Custom {
+		const SIGNATURE: SignatureUnit = u32::SIGNATURE;
+		const IS_DYNAMIC: bool = u32::IS_DYNAMIC;
+		const HEAD_WORDS: u32 = u32::HEAD_WORDS;
+	}
+	impl AbiEncode for Custom {
+		fn enc(&self, out: &mut AbiEncoder) {
+			self.0.enc(out);
+		}
+	}
+	impl AbiDecode for Custom {
+		fn dec(input: &mut AbiDecoder<'_>) -> Result<Self> {
+			u32::dec(input).map(Self)
+		}
+	}
+	#[cfg(feature = "stubgen")]
+	impl evm_coder::solidity::SolidityTypeName for Custom {
+		fn solidity_name(
+			writer: &mut impl core::fmt::Write,
+			tc: &evm_coder::solidity::TypeCollector,
+		) -> core::fmt::Result {
+			u32::solidity_name(writer, tc)
+		}
+		fn is_simple() -> bool {
+			true
+		}
+		fn solidity_default(
+			writer: &mut impl core::fmt::Write,
+			tc: &evm_coder::solidity::TypeCollector,
+		) -> core::fmt::Result {
+			u32::solidity_default(writer, tc)
+		}
+	}
+
+	#[derive(AbiCoder, Debug, PartialEq)]
+	struct WithCustom {
+		custom: Custom,
+		other: u8,
+	}
+
+	#[derive(AbiCoder, Debug, PartialEq)]
+	enum TaggedWithCustom {
+		Custom(Custom),
+		Other(u8),
+	}
+
+	#[test]
+	fn codec() {
+		let value = WithCustom {
+			custom: Custom(1),
+			other: 2,
+		};
+		let encoded = value.abi_encode();
+		similar_asserts::assert_eq!(encoded, (1u32, 2u8).abi_encode());
+		assert_eq!(WithCustom::abi_decode(&encoded).unwrap(), value);
+
+		let value = TaggedWithCustom::Custom(Custom(3));
+		assert_eq!(
+			TaggedWithCustom::abi_decode(&value.abi_encode()).unwrap(),
+			value
+		);
+	}
 }
 
 #[cfg(feature = "bondrewd")]
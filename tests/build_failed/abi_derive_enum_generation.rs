@@ -8,8 +8,8 @@ enum NonRepr {
 }
 
 #[derive(AbiCoder)]
-#[repr(u32)]
-enum NonReprU8 {
+#[repr(u64)]
+enum UnsupportedRepr {
 	A,
 	B,
 	C,
@@ -17,16 +17,8 @@ enum NonReprU8 {
 
 #[derive(AbiCoder)]
 #[repr(u8)]
-enum RustEnum {
-	A(u128),
-	B,
-	C,
-}
-
-#[derive(AbiCoder)]
-#[repr(u8)]
-enum WithExplicit {
-	A = 128,
+enum WithExpression {
+	A = 1 << 7,
 	B,
 	C,
 }
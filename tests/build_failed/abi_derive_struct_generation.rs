@@ -6,6 +6,33 @@ struct EmptyStruct {}
 #[derive(AbiCoder, PartialEq, Debug)]
 struct EmptyTupleStruct();
 
+#[derive(AbiCoder, PartialEq, Debug)]
+struct AllSkippedStruct {
+	#[abi(skip)]
+	a: u8,
+}
+
+#[derive(AbiCoder, PartialEq, Debug)]
+struct SkippedRenamedStruct {
+	#[abi(skip, rename = "b")]
+	a: u8,
+	c: u8,
+}
+
+#[derive(AbiCoder, PartialEq, Debug)]
+struct RenamedTwiceStruct {
+	#[abi(rename = "b")]
+	#[abi(rename = "c")]
+	a: u8,
+}
+
+#[derive(AbiCoder, PartialEq, Debug)]
+struct BytesWithStruct {
+	#[abi(bytes)]
+	#[abi(with = other)]
+	a: Vec<u8>,
+}
+
 fn main() {
 	assert!(false);
 }
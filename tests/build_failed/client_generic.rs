@@ -0,0 +1,22 @@
+use std::marker::PhantomData;
+
+use evm_coder::{dummy_contract, solidity_interface, types::*};
+
+type Result<T> = core::result::Result<T, String>;
+
+pub struct Generic<T>(PhantomData<T>);
+dummy_contract! {
+	macro_rules! Generic_result {...}
+	impl<T> Contract for Generic<T> {...}
+}
+
+#[solidity_interface(name = GenericIs, client = GenericIsClient)]
+impl<T> Generic<T> {
+	fn test(&self) -> Result<u8> {
+		Ok(0)
+	}
+}
+
+fn main() {
+	assert!(false);
+}
@@ -0,0 +1,35 @@
+use evm_coder::{dummy_contract, solidity_interface, types::*};
+
+type Result<T> = core::result::Result<T, String>;
+
+pub struct WithInput;
+dummy_contract! {
+	macro_rules! WithInput_result {...}
+	impl Contract for WithInput {...}
+}
+
+#[solidity_interface(name = WithInput)]
+impl WithInput {
+	#[solidity(fallback)]
+	fn fallback(&mut self, _input: Bytes) -> Result<()> {
+		Ok(())
+	}
+}
+
+pub struct WithoutInput;
+dummy_contract! {
+	macro_rules! WithoutInput_result {...}
+	impl Contract for WithoutInput {...}
+}
+
+#[solidity_interface(name = WithoutInput)]
+impl WithoutInput {
+	#[solidity(fallback)]
+	fn fallback(&mut self) -> Result<Bytes> {
+		Ok(Bytes(Vec::new()))
+	}
+}
+
+fn main() {
+	assert!(false);
+}
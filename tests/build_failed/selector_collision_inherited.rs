@@ -0,0 +1,37 @@
+use evm_coder::{abi::AbiType, dummy_contract, solidity_interface, types::*};
+
+type Result<T> = core::result::Result<T, String>;
+
+pub struct Contract;
+dummy_contract! {
+	macro_rules! Contract_result {...}
+	impl Contract for Contract {...}
+}
+
+#[solidity_interface(name = A)]
+impl Contract {
+	fn name(&self) -> Result<String> {
+		Ok("a".into())
+	}
+}
+
+#[solidity_interface(name = B)]
+impl Contract {
+	#[solidity(rename_selector = "name")]
+	fn b_name(&self) -> Result<String> {
+		Ok("b".into())
+	}
+}
+
+#[solidity_interface(name = Inherited, is(A, B))]
+impl Contract {}
+
+// Collision with ERC165 selector, which is implemented by every interface
+#[solidity_interface(name = Erc165)]
+impl Contract {
+	fn supports_interface(&self, _interface_id: Bytes4) -> Result<bool> {
+		Ok(false)
+	}
+}
+
+fn main() {}
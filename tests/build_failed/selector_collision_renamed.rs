@@ -0,0 +1,22 @@
+use evm_coder::{abi::AbiType, dummy_contract, solidity_interface, types::*};
+
+type Result<T> = core::result::Result<T, String>;
+
+pub struct Contract;
+dummy_contract! {
+	macro_rules! Contract_result {...}
+	impl Contract for Contract {...}
+}
+
+#[solidity_interface(name = Renamed)]
+impl Contract {
+	fn transfer(&mut self, _to: Address) -> Result<()> {
+		Ok(())
+	}
+	#[solidity(rename_selector = "transfer")]
+	fn transfer_to(&mut self, _to: Address) -> Result<()> {
+		Ok(())
+	}
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use evm_coder::{types::Address, ToLog};
+
+#[derive(ToLog)]
+enum Events {
+	Transfer {
+		#[indexed]
+		matches: Address,
+		value: u32,
+	},
+}
+
+#[derive(ToLog)]
+struct Approval {
+	#[indexed]
+	topics: u32,
+}
+
+fn main() {
+	assert!(false);
+}
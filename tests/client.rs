@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+
+use evm_coder::{
+	abi::AbiType,
+	client::{ClientError, Transport},
+	dummy_contract, solidity_interface,
+	types::*,
+	Call, Callable,
+};
+use primitive_types::U256;
+
+type Result<T> = core::result::Result<T, String>;
+
+#[derive(Default)]
+pub struct Token {
+	balances: BTreeMap<Address, U256>,
+	deposits: U256,
+}
+dummy_contract! {
+	macro_rules! Token_result {...}
+	impl Contract for Token {...}
+}
+
+#[solidity_interface(name = TokenMetadata, client = TokenMetadataClient)]
+impl Token {
+	fn name(&self) -> Result<String> {
+		Ok("Token".into())
+	}
+}
+
+#[solidity_interface(name = Token, is(TokenMetadata), client = TokenClient)]
+impl Token {
+	fn decimals() -> Result<u8> {
+		Ok(18)
+	}
+	/// Get balance of specified owner
+	fn balance_of(&self, owner: Address) -> Result<U256> {
+		Ok(self.balances.get(&owner).copied().unwrap_or_default())
+	}
+	fn transfer(&mut self, caller: Caller, to: Address, value: U256) -> Result<bool> {
+		let from = self.balances.entry(caller).or_default();
+		if *from < value {
+			return Err("insufficient balance".into());
+		}
+		*from -= value;
+		*self.balances.entry(to).or_default() += value;
+		Ok(true)
+	}
+	fn deposit(&mut self, caller: Caller, value: Value) -> Result<()> {
+		self.deposits += value;
+		*self.balances.entry(caller).or_default() += value;
+		Ok(())
+	}
+	fn owners(&self) -> Result<Vec<Address>> {
+		Ok(self.balances.keys().copied().collect())
+	}
+}
+
+/// Dispatches calls to local contract, without EVM
+struct LocalTransport<'c> {
+	contract: &'c mut Token,
+	caller: Address,
+}
+impl Transport for LocalTransport<'_> {
+	type Error = String;
+
+	fn call(&mut self, _to: Address, data: Vec<u8>, value: U256) -> Result<Vec<u8>> {
+		let call = TokenCall::parse_full(&data)
+			.map_err(|e| e.to_string())?
+			.ok_or("unknown method")?;
+		<Token as Callable<TokenCall>>::call(
+			self.contract,
+			Msg {
+				call,
+				caller: self.caller,
+				value,
+			},
+		)
+		.map(|post| post.0)
+		.map_err(|post| post.0)
+	}
+}
+
+#[test]
+fn client_calls() {
+	let alice = Address::repeat_byte(1);
+	let bob = Address::repeat_byte(2);
+	let mut token = Token::default();
+	{
+		let mut client = TokenClient::new(
+			LocalTransport {
+				contract: &mut token,
+				caller: alice,
+			},
+			Address::repeat_byte(0xff),
+		);
+
+		assert_eq!(client.decimals().unwrap(), 18);
+		assert_eq!(client.token_metadata().name().unwrap(), "Token");
+		client.deposit(U256::from(10)).unwrap();
+		assert!(client.transfer(bob, U256::from(3)).unwrap());
+		assert_eq!(client.balance_of(alice).unwrap(), U256::from(7));
+		assert_eq!(client.balance_of(bob).unwrap(), U256::from(3));
+		assert_eq!(client.owners().unwrap(), [alice, bob]);
+
+		assert!(matches!(
+			client.transfer(bob, U256::from(8)),
+			Err(ClientError::Transport(e)) if e == "insufficient balance",
+		));
+	}
+	assert_eq!(token.deposits, U256::from(10));
+}
+
+#[test]
+fn client_bad_result() {
+	struct Garbage;
+	impl Transport for Garbage {
+		type Error = ();
+
+		fn call(
+			&mut self,
+			_to: Address,
+			_data: Vec<u8>,
+			_value: U256,
+		) -> core::result::Result<Vec<u8>, ()> {
+			Ok(Vec::from([1, 2, 3]))
+		}
+	}
+	assert!(matches!(
+		TokenClient::new(Garbage, Address::zero()).decimals(),
+		Err(ClientError::Decode(_))
+	));
+}
+
+#[test]
+fn client_generic_interface() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/build_failed/client_generic.rs");
+}
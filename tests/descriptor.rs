@@ -0,0 +1,154 @@
+use evm_coder::{
+	abi::AbiType, descriptor::Mutability, dummy_contract, fn_selector, solidity_interface, types::*,
+};
+use primitive_types::U256;
+
+type Result<T> = core::result::Result<T, String>;
+
+pub struct Token;
+dummy_contract! {
+	macro_rules! Token_result {...}
+	impl Contract for Token {...}
+}
+
+#[solidity_interface(name = TokenBase)]
+impl Token {
+	fn decimals() -> Result<u8> {
+		Ok(18)
+	}
+}
+
+#[solidity_interface(name = TokenInline)]
+impl Token {
+	fn symbol(&self) -> Result<String> {
+		Ok("TKN".into())
+	}
+}
+
+/// @title Token
+#[solidity_interface(name = Token, is(TokenBase), inline_is(TokenInline))]
+impl Token {
+	/// Transfer tokens
+	fn transfer(&mut self, _caller: Caller, to_address: Address, amount: U256) -> Result<bool> {
+		let _ = (to_address, amount);
+		Ok(true)
+	}
+	#[solidity(rename_selector = "mintTo", hide)]
+	fn mint(&mut self, _value: Value, owners: Vec<(Address, U256)>) -> Result<()> {
+		let _ = owners;
+		Ok(())
+	}
+	fn balance(&self) -> Result<U256> {
+		Ok(U256::zero())
+	}
+	/// Called for unknown selectors
+	#[solidity(fallback)]
+	fn fallback(&mut self) -> Result<()> {
+		Ok(())
+	}
+	#[solidity(receive)]
+	fn receive(&mut self, _value: Value) -> Result<()> {
+		Ok(())
+	}
+}
+
+#[test]
+fn interface_descriptor() {
+	let descriptor = &TokenCall::DESCRIPTOR;
+	assert_eq!(descriptor.name, "Token");
+	assert_eq!(descriptor.interface_id, TokenCall::interface_id());
+	assert_eq!(descriptor.docs, [" @title Token"]);
+	assert_eq!(descriptor.parents.len(), 1);
+	assert_eq!(descriptor.parents[0].name, "TokenBase");
+	assert_eq!(descriptor.inline_parents.len(), 1);
+	assert_eq!(descriptor.inline_parents[0].name, "TokenInline");
+	// Fallback and receive functions have no selectors
+	assert_eq!(
+		descriptor
+			.methods
+			.iter()
+			.map(|m| m.name)
+			.collect::<Vec<_>>(),
+		["transfer", "mint", "balance"]
+	);
+}
+
+#[test]
+fn special_method_descriptor() {
+	let fallback = TokenCall::DESCRIPTOR.fallback.as_ref().unwrap();
+	assert_eq!(fallback.name, "fallback");
+	assert!(!fallback.is_payable);
+	assert_eq!(fallback.docs, [" Called for unknown selectors"]);
+
+	let receive = TokenCall::DESCRIPTOR.receive.as_ref().unwrap();
+	assert_eq!(receive.name, "receive");
+	assert!(receive.is_payable);
+
+	assert!(TokenBaseCall::DESCRIPTOR.fallback.is_none());
+	assert!(TokenBaseCall::DESCRIPTOR.receive.is_none());
+}
+
+#[test]
+fn method_descriptor() {
+	let methods = TokenCall::DESCRIPTOR.methods;
+	let (transfer, mint, balance) = (&methods[0], &methods[1], &methods[2]);
+
+	assert_eq!(transfer.solidity_name, "transfer");
+	assert_eq!(transfer.selector, fn_selector!(transfer(address, uint256)));
+	assert_eq!(transfer.signature(), "transfer(address,uint256)");
+	assert_eq!(
+		transfer
+			.args
+			.iter()
+			.map(|a| (a.name, a.ty()))
+			.collect::<Vec<_>>(),
+		[("toAddress", "address"), ("amount", "uint256")]
+	);
+	assert_eq!(
+		transfer
+			.returns
+			.iter()
+			.map(|r| (r.name, r.ty()))
+			.collect::<Vec<_>>(),
+		[("", "bool")]
+	);
+	assert_eq!(transfer.mutability, Mutability::Mutable);
+	assert!(!transfer.is_payable);
+	assert!(!transfer.hide);
+	assert_eq!(transfer.docs, [" Transfer tokens"]);
+
+	assert_eq!(mint.solidity_name, "mintTo");
+	assert_eq!(mint.signature(), "mintTo((address,uint256)[])");
+	assert_eq!(mint.args[0].ty(), "(address,uint256)[]");
+	assert!(mint.returns.is_empty());
+	assert!(mint.is_payable);
+	assert!(mint.hide);
+
+	assert_eq!(balance.mutability, Mutability::View);
+	assert!(balance.args.is_empty());
+	assert_eq!(
+		TokenBaseCall::DESCRIPTOR.methods[0].mutability,
+		Mutability::Pure
+	);
+}
+
+#[test]
+fn find_inherited_method() {
+	let descriptor = &TokenCall::DESCRIPTOR;
+	assert_eq!(
+		descriptor
+			.find_method(TokenBaseCall::DECIMALS)
+			.unwrap()
+			.name,
+		"decimals"
+	);
+	assert_eq!(
+		descriptor
+			.find_method(TokenInlineCall::SYMBOL)
+			.unwrap()
+			.name,
+		"symbol"
+	);
+	assert!(descriptor.find_method(BytesFixed([0; 4])).is_none());
+	assert_eq!(descriptor.iter_methods().count(), 5);
+}
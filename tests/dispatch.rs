@@ -0,0 +1,121 @@
+use std::marker::PhantomData;
+
+use evm_coder::{abi::AbiType, dummy_contract, solidity_interface, types::*, AbiEncode, Call};
+use primitive_types::U256;
+
+type Result<T> = core::result::Result<T, String>;
+
+pub struct Flat;
+dummy_contract! {
+	macro_rules! Flat_result {...}
+	impl Contract for Flat {...}
+}
+
+pub struct Chain<T>(PhantomData<T>);
+dummy_contract! {
+	macro_rules! Chain_result {...}
+	impl<T> Contract for Chain<T> {...}
+}
+
+// Same interface tree, non-generic one is dispatched using flattened selector table,
+// and generic one by asking every parent in turn
+macro_rules! interfaces {
+	($ty:ident, [$($generics:tt)*], $Leaf:ident, $Base:ident, $Inline:ident, $Root:ident) => {
+		#[solidity_interface(name = $Leaf)]
+		impl<$($generics)*> $ty<$($generics)*> {
+			fn leaf(&self, _value: U256) -> Result<()> {
+				Ok(())
+			}
+		}
+
+		#[solidity_interface(name = $Base, is($Leaf))]
+		impl<$($generics)*> $ty<$($generics)*> {
+			fn base_a(&self) -> Result<()> {
+				Ok(())
+			}
+			fn base_b(&self, _a: Address, _b: bool) -> Result<()> {
+				Ok(())
+			}
+		}
+
+		#[solidity_interface(name = $Inline)]
+		impl<$($generics)*> $ty<$($generics)*> {
+			fn inline(&self) -> Result<()> {
+				Ok(())
+			}
+		}
+
+		#[solidity_interface(name = $Root, is($Base, $Leaf), inline_is($Inline))]
+		impl<$($generics)*> $ty<$($generics)*> {
+			fn root(&self, _value: u8) -> Result<()> {
+				Ok(())
+			}
+		}
+	};
+}
+interfaces!(Flat, [], FlatLeaf, FlatBase, FlatInline, FlatRoot);
+interfaces!(Chain, [T], ChainLeaf, ChainBase, ChainInline, ChainRoot);
+
+fn parse<C: Call>(selector: Bytes4, args: impl AbiEncode) -> Option<C> {
+	C::parse(selector, &args.abi_encode()).unwrap()
+}
+
+#[test]
+fn flattened_dispatch() {
+	assert!(matches!(
+		parse(FlatRootCall::ROOT, (7u8,)),
+		Some(FlatRootCall::Root { _value: 7 })
+	));
+	assert!(matches!(
+		parse(FlatInlineCall::INLINE, ()),
+		Some(FlatRootCall::FlatInline(FlatInlineCall::Inline))
+	));
+	assert!(matches!(
+		parse(FlatBaseCall::BASE_B, (Address::zero(), true)),
+		Some(FlatRootCall::FlatBase(FlatBaseCall::BaseB { _b: true, .. }))
+	));
+	// Leaf is inherited twice, first path wins
+	assert!(matches!(
+		parse(FlatLeafCall::LEAF, (U256::one(),)),
+		Some(FlatRootCall::FlatBase(FlatBaseCall::FlatLeaf(
+			FlatLeafCall::Leaf { .. }
+		)))
+	));
+	assert!(parse::<FlatRootCall>(BytesFixed([0; 4]), ()).is_none());
+	assert!(matches!(
+		parse(
+			evm_coder::ERC165Call::INTERFACE_ID,
+			(FlatRootCall::interface_id(),)
+		),
+		Some(FlatRootCall::ERC165Call(..))
+	));
+}
+
+#[test]
+fn flattened_dispatch_matches_chain() {
+	let selectors = (0..FlatRootCall::SELECTOR_COUNT)
+		.map(FlatRootCall::selector_at)
+		.collect::<Vec<_>>();
+	assert_eq!(
+		selectors,
+		(0..ChainRootCall::<()>::SELECTOR_COUNT)
+			.map(ChainRootCall::<()>::selector_at)
+			.collect::<Vec<_>>()
+	);
+	for selector in selectors {
+		let input = Vec::from([0; 64]);
+		assert_eq!(
+			format!("{:?}", FlatRootCall::parse(selector, &input).unwrap()).replace("Flat", ""),
+			format!(
+				"{:?}",
+				ChainRootCall::<()>::parse(selector, &input).unwrap()
+			)
+			.replace("Chain", ""),
+		);
+	}
+}
+
+#[test]
+fn bad_arguments() {
+	assert!(FlatRootCall::parse(FlatBaseCall::BASE_B, &[]).is_err());
+}
@@ -0,0 +1,210 @@
+use evm_coder::{
+	abi::AbiType, dummy_contract, fn_selector, solidity_interface, types::*, Callable, FromLog,
+	ToLog,
+};
+use primitive_types::U256;
+
+type Result<T> = core::result::Result<T, String>;
+
+#[derive(ToLog, FromLog, Debug, PartialEq)]
+enum TokenEvents {
+	Transfer {
+		#[indexed]
+		from: Address,
+		#[indexed]
+		to: Address,
+		value: U256,
+	},
+}
+
+pub struct Token;
+dummy_contract! {
+	macro_rules! Token_result {...}
+	impl Contract for Token {...}
+}
+
+#[solidity_interface(name = TokenBase, events(TokenEvents))]
+impl Token {
+	fn transfer(
+		&mut self,
+		caller: Caller,
+		to: Address,
+		value: U256,
+		events: &mut EventSink,
+	) -> Result<bool> {
+		events.emit(TokenEvents::Transfer {
+			from: caller,
+			to,
+			value,
+		});
+		Ok(true)
+	}
+	fn balance_of(&self, _owner: Address) -> Result<U256> {
+		Ok(U256::zero())
+	}
+	fn transfer_from(
+		&mut self,
+		from: Address,
+		to: Address,
+		value: U256,
+		events: &mut evm_coder::types::EventSink,
+	) -> Result<bool> {
+		events.emit(TokenEvents::Transfer { from, to, value });
+		Err("allowance exceeded".into())
+	}
+}
+
+#[solidity_interface(name = Token, is(TokenBase))]
+impl Token {
+	fn burn(&mut self, caller: Caller, value: U256, events: &mut EventSink) -> Result<()> {
+		events.emit(TokenEvents::Transfer {
+			from: caller,
+			to: Address::zero(),
+			value,
+		});
+		events.emit(TokenEvents::Transfer {
+			from: Address::zero(),
+			to: Address::zero(),
+			value: U256::zero(),
+		});
+		Ok(())
+	}
+	fn burn_from(&mut self, from: Address, value: U256, events: &mut EventSink) -> Result<()> {
+		events.emit(TokenEvents::Transfer {
+			from,
+			to: Address::zero(),
+			value,
+		});
+		Err("allowance exceeded".into())
+	}
+}
+
+#[test]
+fn event_sink_is_not_in_signature() {
+	assert_eq!(
+		TokenBaseCall::TRANSFER,
+		fn_selector!(transfer(address, uint256))
+	);
+	assert_eq!(
+		TokenBaseCall::TRANSFER_FROM,
+		fn_selector!(transferFrom(address, address, uint256))
+	);
+}
+
+#[test]
+fn events_are_collected() {
+	let contract = Address::repeat_byte(0xff);
+	let caller = Address::repeat_byte(1);
+	let mut events = EventSink::new(contract);
+	let result = <Token as Callable<TokenCall>>::call_with_events(
+		&mut Token,
+		Msg {
+			call: TokenCall::TokenBase(TokenBaseCall::Transfer {
+				to: Address::repeat_byte(2),
+				value: U256::from(3),
+			}),
+			caller,
+			value: U256::zero(),
+		},
+		&mut events,
+	);
+	assert!(result.is_ok());
+	let result = <Token as Callable<TokenCall>>::call_with_events(
+		&mut Token,
+		Msg {
+			call: TokenCall::Burn {
+				value: U256::from(4),
+			},
+			caller,
+			value: U256::zero(),
+		},
+		&mut events,
+	);
+	assert!(result.is_ok());
+
+	assert!(events.logs().iter().all(|log| log.address == contract));
+	let events = events
+		.into_logs()
+		.iter()
+		.map(|log| TokenEvents::from_log(log).unwrap().unwrap())
+		.collect::<Vec<_>>();
+	assert_eq!(
+		events,
+		[
+			TokenEvents::Transfer {
+				from: caller,
+				to: Address::repeat_byte(2),
+				value: U256::from(3),
+			},
+			TokenEvents::Transfer {
+				from: caller,
+				to: Address::zero(),
+				value: U256::from(4),
+			},
+			TokenEvents::Transfer {
+				from: Address::zero(),
+				to: Address::zero(),
+				value: U256::zero(),
+			},
+		]
+	);
+}
+
+#[test]
+fn events_are_discarded_by_call() {
+	let result = <Token as Callable<TokenCall>>::call(
+		&mut Token,
+		Msg {
+			call: TokenCall::Burn {
+				value: U256::from(4),
+			},
+			caller: Address::repeat_byte(1),
+			value: U256::zero(),
+		},
+	);
+	assert!(result.is_ok());
+}
+
+#[test]
+fn events_are_reverted_on_error() {
+	let contract = Address::repeat_byte(0xff);
+	let caller = Address::repeat_byte(1);
+	let mut events = EventSink::new(contract);
+	let result = <Token as Callable<TokenCall>>::call_with_events(
+		&mut Token,
+		Msg {
+			call: TokenCall::Burn {
+				value: U256::from(4),
+			},
+			caller,
+			value: U256::zero(),
+		},
+		&mut events,
+	);
+	assert!(result.is_ok());
+	assert_eq!(events.logs().len(), 2);
+
+	for call in [
+		TokenCall::BurnFrom {
+			from: Address::repeat_byte(2),
+			value: U256::from(5),
+		},
+		TokenCall::TokenBase(TokenBaseCall::TransferFrom {
+			from: Address::repeat_byte(2),
+			to: Address::repeat_byte(3),
+			value: U256::from(6),
+		}),
+	] {
+		let result = <Token as Callable<TokenCall>>::call_with_events(
+			&mut Token,
+			Msg {
+				call,
+				caller,
+				value: U256::zero(),
+			},
+			&mut events,
+		);
+		assert!(result.is_err());
+		assert_eq!(events.logs().len(), 2);
+	}
+}
@@ -0,0 +1,114 @@
+use evm_coder::{dummy_contract, solidity_interface, types::*, Call, Callable};
+use primitive_types::U256;
+
+type Result<T> = core::result::Result<T, String>;
+
+#[derive(Default)]
+pub struct Wallet {
+	received: U256,
+	unknown_calls: Vec<Bytes>,
+}
+dummy_contract! {
+	macro_rules! Wallet_result {...}
+	impl Contract for Wallet {...}
+}
+
+#[solidity_interface(name = WalletBase)]
+impl Wallet {
+	fn received(&self) -> Result<U256> {
+		Ok(self.received)
+	}
+	/// Accept plain transfers
+	#[solidity(receive)]
+	fn receive(&mut self, value: Value) -> Result<()> {
+		self.received += value;
+		Ok(())
+	}
+	/// Echo unknown calls
+	#[solidity(fallback)]
+	fn fallback(&mut self, input: Bytes) -> Result<Bytes> {
+		self.unknown_calls.push(input.clone());
+		Ok(input)
+	}
+}
+
+#[solidity_interface(name = Wallet, is(WalletBase))]
+impl Wallet {
+	fn owner(&self) -> Result<Address> {
+		Ok(Address::zero())
+	}
+}
+
+fn call(wallet: &mut Wallet, data: &[u8], value: U256) -> Vec<u8> {
+	let call = WalletCall::parse_full(data).unwrap().unwrap();
+	<Wallet as Callable<WalletCall>>::call(
+		wallet,
+		Msg {
+			call,
+			caller: Address::zero(),
+			value,
+		},
+	)
+	.map(|post| post.0)
+	.map_err(|post| post.0)
+	.unwrap()
+}
+
+#[test]
+fn empty_calldata_is_received() {
+	assert!(matches!(
+		WalletCall::parse_full(&[]),
+		Ok(Some(WalletCall::WalletBase(WalletBaseCall::Receive)))
+	));
+
+	let mut wallet = Wallet::default();
+	assert_eq!(call(&mut wallet, &[], U256::from(5)), Vec::<u8>::new());
+	assert_eq!(wallet.received, U256::from(5));
+}
+
+#[test]
+fn unknown_selector_is_fallback() {
+	let data = [0x12, 0x34, 0x56, 0x78, 0x9a];
+	let mut wallet = Wallet::default();
+	assert_eq!(call(&mut wallet, &data, U256::zero()), data);
+	assert_eq!(call(&mut wallet, &[1, 2], U256::zero()), [1, 2]);
+	assert_eq!(
+		wallet.unknown_calls,
+		[Bytes(Vec::from(data)), Bytes(Vec::from([1, 2]))]
+	);
+
+	// Known selectors are not affected
+	assert!(matches!(
+		WalletCall::parse_full(&WalletCall::OWNER.0),
+		Ok(Some(WalletCall::Owner))
+	));
+}
+
+#[test]
+fn no_selectors_for_special_functions() {
+	assert_eq!(WalletBaseCall::interface_id(), WalletBaseCall::RECEIVED);
+}
+
+#[cfg(feature = "stubgen")]
+#[test]
+fn special_functions_stub() {
+	let tc = evm_coder::solidity::TypeCollector::new();
+	WalletBaseCall::generate_solidity_interface(&tc, false);
+	let out = tc.finish().join("");
+	assert!(
+		out.contains(
+			"\t/// Accept plain transfers
+	receive() external payable;
+	/// Echo unknown calls
+	fallback(bytes calldata input) external returns (bytes memory);
+"
+		),
+		"{out}"
+	);
+}
+
+#[test]
+fn fallback_output_matches_input() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/build_failed/fallback_output.rs");
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "stubgen")]
+
+use evm_coder::{abi::AbiType, dummy_contract, solidity_interface, types::*, ToLog};
+use primitive_types::U256;
+
+type Result<T> = core::result::Result<T, String>;
+
+pub struct Token;
+dummy_contract! {
+	macro_rules! Token_result {...}
+	impl Contract for Token {...}
+}
+
+#[derive(ToLog)]
+pub enum InlineEvents {
+	Burned { amount: U256 },
+}
+
+#[solidity_interface(name = TokenBase)]
+impl Token {
+	fn decimals(&self) -> Result<u8> {
+		unreachable!()
+	}
+}
+
+#[solidity_interface(name = TokenBurnable, is(TokenBase), events(InlineEvents))]
+impl Token {
+	fn burn(&mut self, _amount: U256) -> Result<()> {
+		unreachable!()
+	}
+}
+
+#[solidity_interface(name = TokenMetadata, inline_is(TokenBurnable))]
+impl Token {
+	fn symbol(&self) -> Result<String> {
+		unreachable!()
+	}
+}
+
+#[solidity_interface(name = Token, is(TokenBase), inline_is(TokenMetadata))]
+impl Token {
+	fn balance_of(&self, _owner: Address) -> Result<U256> {
+		unreachable!()
+	}
+}
+
+fn interface_stub(name: &str) -> String {
+	let tc = evm_coder::solidity::TypeCollector::new();
+	TokenCall::generate_solidity_interface(&tc, false);
+	tc.finish()
+		.into_iter()
+		.find(|stub| stub.contains(&format!("interface {name} ")))
+		.expect("interface is generated")
+}
+
+#[test]
+fn inline_parents_are_not_inherited() {
+	let stub = interface_stub("Token");
+	assert!(
+		stub.contains("interface Token is Dummy, ERC165, TokenBase, InlineEvents {"),
+		"{stub}"
+	);
+	for function in ["balanceOf", "symbol", "burn"] {
+		assert!(stub.contains(&format!("function {function}(")), "{stub}");
+	}
+	assert!(!stub.contains("function decimals("), "{stub}");
+}
+
+#[test]
+fn solidity_interface_id_matches() {
+	let stub = interface_stub("Token");
+	let solidity_id = stub
+		.lines()
+		.filter_map(|line| line.split("EVM selector for this function is: 0x").nth(1))
+		.map(|selector| u32::from_str_radix(&selector[..8], 16).unwrap())
+		.fold(0, |id, selector| id ^ selector);
+	assert_eq!(solidity_id, u32::from_be_bytes(TokenCall::interface_id().0));
+	assert!(
+		stub.contains(&format!(
+			"the ERC-165 identifier for this interface is 0x{solidity_id:0>8x}"
+		)),
+		"{stub}"
+	);
+}
@@ -1,7 +1,11 @@
 #![allow(dead_code)]
 
-use evm_coder::{types::*, ToLog};
-use primitive_types::U256;
+use evm_coder::{
+	events::{hash_topic, FromLogError, FromTopic, LogsBloom},
+	types::*,
+	AbiCoder, FromLog, ToLog, ToTopic,
+};
+use primitive_types::{H256, U256};
 
 #[derive(ToLog)]
 enum ERC721Log {
@@ -18,3 +22,562 @@ enum ERC721Log {
 		bbb: U256,
 	},
 }
+
+#[derive(ToLog, FromLog, Debug, PartialEq)]
+enum Events {
+	Transfer {
+		#[indexed]
+		from: Address,
+		#[indexed]
+		id: u32,
+		value: U256,
+	},
+	Empty {},
+	#[anonymous]
+	Anonymous {
+		#[indexed]
+		a: u32,
+		#[indexed]
+		b: u32,
+		#[indexed]
+		c: u32,
+		#[indexed]
+		d: Address,
+		value: U256,
+	},
+}
+
+#[test]
+fn from_log() {
+	let contract = Address::repeat_byte(0xff);
+	for event in [
+		Events::Transfer {
+			from: Address::repeat_byte(1),
+			id: 2,
+			value: U256::from(3),
+		},
+		Events::Empty {},
+	] {
+		let log = event.to_log(contract);
+		assert_eq!(Events::from_log(&log).unwrap(), Some(event));
+	}
+}
+
+#[test]
+fn from_log_anonymous() {
+	let event = Events::Anonymous {
+		a: 1,
+		b: 2,
+		c: 3,
+		d: Address::repeat_byte(4),
+		value: U256::from(5),
+	};
+	let log = event.to_log(Address::repeat_byte(0xff));
+	assert_eq!(Events::from_log(&log).unwrap(), None);
+	assert_eq!(Events::from_log_anonymous(&log), Some(event));
+}
+
+/// Emitted by some other contract, but has the same shape as `Events::Anonymous`
+#[derive(ToLog)]
+enum ForeignEvents {
+	#[anonymous]
+	Foreign {
+		#[indexed]
+		kind: u8,
+		#[indexed]
+		x: u32,
+		#[indexed]
+		y: u32,
+		#[indexed]
+		owner: Address,
+		amount: U256,
+	},
+}
+
+#[test]
+fn from_log_anonymous_is_not_fallback() {
+	let log = ForeignEvents::Foreign {
+		kind: 1,
+		x: 2,
+		y: 3,
+		owner: Address::zero(),
+		amount: U256::zero(),
+	}
+	.to_log(Address::zero());
+	assert_eq!(Events::from_log(&log).unwrap(), None);
+	assert!(Events::from_log_anonymous(&log).is_some());
+}
+
+#[test]
+fn from_log_foreign() {
+	let log = ERC721Log::Eee {
+		aaa: Address::zero(),
+		bbb: U256::zero(),
+	}
+	.to_log(Address::zero());
+	assert_eq!(Events::from_log(&log).unwrap(), None);
+}
+
+#[test]
+fn from_log_malformed() {
+	let mut log = Events::Transfer {
+		from: Address::repeat_byte(1),
+		id: 2,
+		value: U256::from(3),
+	}
+	.to_log(Address::zero());
+	log.data.pop();
+	assert!(matches!(Events::from_log(&log), Err(FromLogError::Data(_))));
+	log.topics[2] = H256::repeat_byte(1);
+	assert!(matches!(
+		Events::from_log(&log),
+		Err(FromLogError::Topic(_))
+	));
+	log.topics.pop();
+	assert!(matches!(
+		Events::from_log(&log),
+		Err(FromLogError::TopicCount)
+	));
+}
+
+#[test]
+fn anonymous_without_selector() {
+	let log = Events::Anonymous {
+		a: 1,
+		b: 2,
+		c: 3,
+		d: Address::repeat_byte(4),
+		value: U256::from(5),
+	}
+	.to_log(Address::zero());
+	assert_eq!(log.topics.len(), 4);
+	assert_eq!(log.topics[0], 1u32.to_topic());
+}
+
+#[derive(AbiCoder, Default, Clone, Copy, Debug, PartialEq)]
+#[repr(u8)]
+enum Kind {
+	#[default]
+	A,
+	B,
+}
+
+#[derive(AbiCoder)]
+struct Info {
+	id: u32,
+	name: String,
+}
+
+#[derive(ToLog)]
+enum ReferenceEvents {
+	Created {
+		#[indexed]
+		name: String,
+		#[indexed]
+		info: Info,
+		#[indexed]
+		kind: Kind,
+	},
+}
+
+#[test]
+fn indexed_reference_types() {
+	let log = ReferenceEvents::Created {
+		name: "hello".into(),
+		info: Info {
+			id: 1,
+			name: "hello".into(),
+		},
+		kind: Kind::B,
+	}
+	.to_log(Address::zero());
+	assert_eq!(
+		log.topics[1],
+		H256(hex_literal::hex!(
+			"1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8"
+		))
+	);
+
+	let mut info = [0u8; 64];
+	info[31] = 1;
+	info[32..37].copy_from_slice(b"hello");
+	assert_eq!(log.topics[2], hash_topic(&info));
+	assert_eq!(log.topics[3], 1u8.to_topic());
+	assert_eq!(Kind::from_topic(&log.topics[3]).unwrap(), Kind::B);
+}
+
+/// Encodes `String` without copying it
+mod str_ref {
+	use evm_coder::{
+		abi::{AbiEncode, AbiEncoder, AbiType, Result},
+		custom_signature::SignatureUnit,
+	};
+
+	pub type Abi = String;
+	pub struct Ref<'a>(&'a str);
+	impl AbiType for Ref<'_> {
+		const SIGNATURE: SignatureUnit = String::SIGNATURE;
+		const IS_DYNAMIC: bool = String::IS_DYNAMIC;
+		const HEAD_WORDS: u32 = String::HEAD_WORDS;
+	}
+	impl AbiEncode for Ref<'_> {
+		fn enc(&self, out: &mut AbiEncoder) {
+			self.0.enc(out);
+		}
+	}
+	#[allow(clippy::ptr_arg)]
+	pub fn encode(value: &String) -> Ref<'_> {
+		Ref(value)
+	}
+	pub fn decode(value: Abi) -> Result<String> {
+		Ok(value)
+	}
+}
+
+#[derive(AbiCoder)]
+struct InfoWith {
+	id: u32,
+	#[abi(with = str_ref)]
+	name: String,
+}
+
+#[derive(ToLog)]
+struct InfoCreated {
+	#[indexed]
+	info: InfoWith,
+}
+
+#[test]
+fn indexed_with_field() {
+	let log = InfoCreated {
+		info: InfoWith {
+			id: 1,
+			name: "hello".into(),
+		},
+	}
+	.to_log(Address::zero());
+	let info = Info {
+		id: 1,
+		name: "hello".into(),
+	};
+	assert_eq!(log.topics[1], info.to_topic());
+}
+
+#[test]
+fn indexed_value_types() {
+	assert_eq!(true.to_topic(), H256::from_low_u64_be(1));
+	assert_eq!(0x1234u16.to_topic(), H256::from_low_u64_be(0x1234));
+	let mut fixed = [0u8; 32];
+	fixed[..2].copy_from_slice(&[1, 2]);
+	assert_eq!(BytesFixed([1u8, 2]).to_topic(), H256(fixed));
+	assert_eq!(
+		<BytesFixed<2>>::from_topic(&H256(fixed)).unwrap(),
+		BytesFixed([1, 2])
+	);
+	assert!(u8::from_topic(&H256::from_low_u64_be(0x100)).is_err());
+	assert_eq!(
+		Bytes(vec![]).to_topic(),
+		H256(hex_literal::hex!(
+			"c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+		))
+	);
+	assert_eq!(
+		vec![1u32, 2].to_topic(),
+		hash_topic(&[1u32.to_topic().0, 2u32.to_topic().0].concat())
+	);
+}
+
+#[test]
+fn filter() {
+	let from = Address::repeat_byte(1);
+	let filter = ERC721Log::transfer_filter().from(from);
+	assert_eq!(
+		filter.topics(),
+		&[Some(H256(ERC721Log::TRANSFER)), Some(from.to_topic()), None]
+	);
+	let transfer = |from, to| {
+		ERC721Log::Transfer {
+			from,
+			to,
+			value: U256::zero(),
+		}
+		.to_log(Address::zero())
+	};
+	assert!(filter.matches(&transfer(from, Address::repeat_byte(2))));
+	assert!(filter.matches(&transfer(from, Address::repeat_byte(3))));
+	assert!(!filter.matches(&transfer(Address::repeat_byte(2), from)));
+	assert!(!filter.matches(
+		&ERC721Log::Eee {
+			aaa: from,
+			bbb: U256::zero(),
+		}
+		.to_log(Address::zero())
+	));
+
+	let anonymous = Events::anonymous_filter().c(3);
+	assert_eq!(
+		anonymous.topics(),
+		&[None, None, Some(3u32.to_topic()), None]
+	);
+}
+
+#[test]
+fn logs_bloom() {
+	use hex_literal::hex;
+
+	let address = Address::from(hex!("ef2d6d194084c2de36e0dabfce45d046b37d1106"));
+	let topic = H256(hex!(
+		"02c69be41d0b7e40352fc85be1cd65eb03d40ef8427a0ca4596b1ead9a00e9fc"
+	));
+	let log = evm_coder::ethereum::Log {
+		address,
+		topics: Vec::from([topic]),
+		data: Vec::new(),
+	};
+	let bloom = LogsBloom::from_logs([&log]);
+	assert_eq!(
+		bloom.0.to_vec(),
+		hex!(
+			"00000000000000000000000000000000
+			00000000100000000000000000000000
+			00000000000000000000000000000000
+			00000000000000000000000000000000
+			00000000000000000000000000000000
+			00000000000000000000000000000000
+			00000002020000000000000000000000
+			00000000000000000000000800000000
+			10000000000000000000000000000000
+			00000000000000000000001000000000
+			00000000000000000000000000000000
+			00000000000000000000000000000000
+			00000000000000000000000000000000
+			00000000000000000000000000000000
+			00000000000000000000000000000000
+			00000000000000000000000000000000"
+		)
+	);
+	assert!(bloom.contains_address(&address));
+	assert!(bloom.contains_topic(&topic));
+	assert!(!LogsBloom::default().contains_topic(&topic));
+}
+
+#[test]
+fn logs_bloom_filter() {
+	let from = Address::repeat_byte(1);
+	let contract = Address::repeat_byte(0xff);
+	let bloom = LogsBloom::from_logs(&[ERC721Log::Transfer {
+		from,
+		to: Address::repeat_byte(2),
+		value: U256::zero(),
+	}
+	.to_log(contract)]);
+
+	assert!(bloom.contains_address(&contract));
+	assert!(bloom.contains_topic(&H256(ERC721Log::TRANSFER)));
+	assert!(!bloom.contains_topic(&H256(ERC721Log::EEE)));
+	assert!(ERC721Log::transfer_filter().from(from).might_match(&bloom));
+	assert!(!ERC721Log::transfer_filter()
+		.from(Address::repeat_byte(3))
+		.might_match(&bloom));
+	assert!(!ERC721Log::eee_filter().might_match(&bloom));
+
+	let mut merged = LogsBloom::default();
+	merged.accrue_bloom(&bloom);
+	assert_eq!(merged, bloom);
+}
+
+/// Renamed events docs
+#[derive(ToLog, FromLog, Debug, PartialEq)]
+enum RenamedEvents {
+	/// Emitted on token transfer
+	#[event(rename = "Transfer")]
+	Moved {
+		/// Previous owner
+		#[indexed]
+		#[event(rename = "from")]
+		source: Address,
+		/// New owner
+		///  which may be a contract
+		#[indexed]
+		to: Address,
+		token_value: U256,
+	},
+}
+
+#[test]
+fn renamed_event_selector() {
+	assert_eq!(
+		RenamedEvents::MOVED,
+		evm_coder::event_topic!(Transfer(address, address, uint256)).0
+	);
+	assert_eq!(RenamedEvents::MOVED, ERC721Log::TRANSFER);
+
+	let event = RenamedEvents::Moved {
+		source: Address::repeat_byte(1),
+		to: Address::repeat_byte(2),
+		token_value: U256::from(3),
+	};
+	let log = event.to_log(Address::zero());
+	assert_eq!(RenamedEvents::from_log(&log).unwrap(), Some(event));
+}
+
+#[cfg(feature = "stubgen")]
+#[test]
+fn renamed_event_stub() {
+	let tc = evm_coder::solidity::TypeCollector::new();
+	RenamedEvents::generate_solidity_interface(&tc, false);
+	similar_asserts::assert_eq!(
+		tc.finish().join(""),
+		"/// @dev inlined interface
+/// Renamed events docs
+interface RenamedEvents {
+	/// Emitted on token transfer
+	/// @param from Previous owner
+	/// @param to New owner
+	///  which may be a contract
+	event Transfer(address indexed from, address indexed to, uint256 tokenValue);
+}
+"
+	);
+}
+
+#[derive(AbiCoder, Debug, PartialEq, Default)]
+struct Page<T> {
+	items: Vec<T>,
+	next: Option<U256>,
+}
+
+#[derive(ToLog, FromLog, Debug, PartialEq)]
+enum ComplexEvents {
+	Complex {
+		#[indexed]
+		id: evm_coder::types::Address,
+		owners: Vec<Address>,
+		limit: Option<U256>,
+		data: evm_coder::types::Bytes,
+		page: Page<u32>,
+	},
+}
+
+#[derive(ToLog)]
+enum IndexedComplexEvents {
+	IndexedComplex {
+		#[indexed]
+		owners: Vec<Address>,
+		#[indexed]
+		page: Page<u32>,
+	},
+}
+
+#[test]
+fn complex_field_types() {
+	assert_eq!(
+		H256(ComplexEvents::COMPLEX),
+		hash_topic(b"Complex(address,address[],(bool,uint256),bytes,(uint32[],(bool,uint256)))")
+	);
+	let event = ComplexEvents::Complex {
+		id: Address::repeat_byte(1),
+		owners: Vec::from([Address::repeat_byte(2), Address::repeat_byte(3)]),
+		limit: Some(U256::from(4)),
+		data: Bytes(Vec::from([5, 6])),
+		page: Page {
+			items: Vec::from([7]),
+			next: None,
+		},
+	};
+	let log = event.to_log(Address::zero());
+	assert_eq!(ComplexEvents::from_log(&log).unwrap(), Some(event));
+
+	assert_eq!(
+		H256(IndexedComplexEvents::INDEXED_COMPLEX),
+		hash_topic(b"IndexedComplex(address[],(uint32[],(bool,uint256)))")
+	);
+	let owners = Vec::from([Address::repeat_byte(1)]);
+	let filter = IndexedComplexEvents::indexed_complex_filter().owners(owners.clone());
+	assert!(filter.matches(
+		&IndexedComplexEvents::IndexedComplex {
+			owners,
+			page: Page::default(),
+		}
+		.to_log(Address::zero())
+	));
+}
+
+#[cfg(feature = "stubgen")]
+#[test]
+fn complex_field_types_stub() {
+	let tc = evm_coder::solidity::TypeCollector::new();
+	IndexedComplexEvents::generate_solidity_interface(&tc, false);
+	let out = tc.finish().join("");
+	assert!(
+		out.contains(
+			"\tevent IndexedComplex(address[] indexed owners, PageUint32 indexed page);\n"
+		),
+		"{out}"
+	);
+}
+
+/// Struct transfer docs
+#[derive(ToLog, FromLog, Debug, PartialEq)]
+struct Transfer {
+	/// Previous owner
+	#[indexed]
+	from: Address,
+	#[indexed]
+	to: Address,
+	value: U256,
+}
+
+#[test]
+fn standalone_event() {
+	assert_eq!(Transfer::TRANSFER, ERC721Log::TRANSFER);
+
+	let event = Transfer {
+		from: Address::repeat_byte(1),
+		to: Address::repeat_byte(2),
+		value: U256::from(3),
+	};
+	let log = event.to_log(Address::repeat_byte(0xff));
+	assert_eq!(
+		log,
+		ERC721Log::Transfer {
+			from: Address::repeat_byte(1),
+			to: Address::repeat_byte(2),
+			value: U256::from(3),
+		}
+		.to_log(Address::repeat_byte(0xff))
+	);
+	assert_eq!(Transfer::from_log(&log).unwrap(), Some(event));
+
+	let filter = Transfer::filter().to(Address::repeat_byte(2));
+	assert!(filter.matches(&log));
+	assert_eq!(
+		filter.topics(),
+		ERC721Log::transfer_filter()
+			.to(Address::repeat_byte(2))
+			.topics()
+	);
+}
+
+#[cfg(feature = "stubgen")]
+#[test]
+fn standalone_event_stub() {
+	let tc = evm_coder::solidity::TypeCollector::new();
+	Transfer::generate_solidity_interface(&tc, false);
+	similar_asserts::assert_eq!(
+		tc.finish().join(""),
+		"/// @dev inlined interface
+interface Transfer {
+	/// Struct transfer docs
+	/// @param from Previous owner
+	event Transfer(address indexed from, address indexed to, uint256 value);
+}
+"
+	);
+}
+
+#[test]
+fn filter_method_conflicts() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/build_failed/to_log_filter_conflict.rs");
+}
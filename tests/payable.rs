@@ -0,0 +1,113 @@
+use evm_coder::{
+	dummy_contract, solidity_interface, types::*, Callable, Contract, DummyPost, ERC165Call,
+	NON_PAYABLE_ERROR,
+};
+use primitive_types::U256;
+
+type Result<T> = core::result::Result<T, String>;
+
+pub struct Strict;
+dummy_contract! {
+	macro_rules! Strict_result {...}
+	impl Contract for Strict {...}
+}
+
+#[solidity_interface(name = StrictBase)]
+impl Strict {
+	fn inherited(&mut self) -> Result<u8> {
+		Ok(1)
+	}
+}
+
+#[solidity_interface(name = Strict, is(StrictBase))]
+impl Strict {
+	fn non_payable(&mut self) -> Result<u8> {
+		Ok(2)
+	}
+	fn payable(&mut self, _value: Value) -> Result<u8> {
+		Ok(3)
+	}
+}
+
+fn call_strict(call: StrictCall, value: u32) -> Result<Vec<u8>> {
+	<Strict as Callable<StrictCall>>::call(
+		&mut Strict,
+		Msg {
+			call,
+			caller: Address::zero(),
+			value: U256::from(value),
+		},
+	)
+	.map(|post| post.0)
+	.map_err(|post| post.0)
+}
+
+#[test]
+fn non_payable_rejects_value() {
+	assert!(call_strict(StrictCall::NonPayable, 0).is_ok());
+	assert_eq!(
+		call_strict(StrictCall::NonPayable, 1).unwrap_err(),
+		NON_PAYABLE_ERROR
+	);
+	assert!(call_strict(StrictCall::Payable, 1).is_ok());
+}
+
+#[test]
+fn inherited_non_payable_rejects_value() {
+	let inherited = || StrictCall::StrictBase(StrictBaseCall::Inherited);
+	assert!(call_strict(inherited(), 0).is_ok());
+	assert_eq!(call_strict(inherited(), 1).unwrap_err(), NON_PAYABLE_ERROR);
+
+	let supports_interface = StrictCall::ERC165Call(
+		ERC165Call::SupportsInterface {
+			interface_id: StrictCall::interface_id(),
+		},
+		Default::default(),
+	);
+	assert_eq!(
+		call_strict(supports_interface, 1).unwrap_err(),
+		NON_PAYABLE_ERROR
+	);
+}
+
+/// Contract, which checks payments by itself
+pub struct Lenient;
+impl Contract for Lenient {
+	type Error = String;
+	type WithPostInfo<T> = DummyPost<T>;
+	type Result<T, E> = core::result::Result<T, E>;
+
+	const REJECT_NON_PAYABLE_VALUE: bool = false;
+
+	fn map_post<I, O>(v: DummyPost<I>, mapper: impl FnOnce(I) -> O) -> DummyPost<O> {
+		DummyPost(mapper(v.0))
+	}
+	fn with_default_post<T>(v: T) -> DummyPost<T> {
+		DummyPost(v)
+	}
+}
+macro_rules! Lenient_result {
+	($i:expr) => {
+		$i.map(DummyPost).map_err(DummyPost)
+	};
+}
+
+#[solidity_interface(name = Lenient)]
+impl Lenient {
+	fn non_payable(&mut self) -> Result<u8> {
+		Ok(2)
+	}
+}
+
+#[test]
+fn non_payable_check_opt_out() {
+	let result = <Lenient as Callable<LenientCall>>::call(
+		&mut Lenient,
+		Msg {
+			call: LenientCall::NonPayable,
+			caller: Address::zero(),
+			value: U256::one(),
+		},
+	);
+	assert!(result.is_ok());
+}
@@ -38,11 +38,18 @@ enum OurEvents {
 	},
 }
 
+#[derive(ToLog)]
+struct OurStructEvent {
+	#[indexed]
+	field1: u32,
+	field2: Address,
+}
+
 #[solidity_interface(
 	name = OurInterface2,
 	is(OurInterface),
 	inline_is(OurInterface1),
-	events(OurEvents)
+	events(OurEvents, OurStructEvent)
 )]
 impl Impls {
 	#[solidity(rename_selector = "fnK")]
@@ -64,6 +71,14 @@ impl Impls {
 	fn with_doc(&self) -> Result<()> {
 		unreachable!()
 	}
+
+	fn option_representations(
+		&self,
+		_array: OptionAsArray<u32>,
+		_zero: OptionAsZero<Address>,
+	) -> Result<OptionAsArray<String>> {
+		unreachable!()
+	}
 }
 
 #[solidity_interface(
@@ -0,0 +1,154 @@
+use evm_coder::{
+	abi::AbiType, client::Transport, dummy_contract, solidity_interface, types::*, Call, Callable,
+};
+use hex_literal::hex;
+use primitive_types::U256;
+
+type Result<T> = core::result::Result<T, String>;
+
+pub struct Token;
+dummy_contract! {
+	macro_rules! Token_result {...}
+	impl Contract for Token {...}
+}
+
+#[solidity_interface(name = Token, client = TokenClient)]
+impl Token {
+	/// @return balance Balance of owner
+	#[solidity(returns(balance, frozen))]
+	fn account(&self, _owner: Address) -> Result<(U256, bool)> {
+		Ok((U256::from(5), true))
+	}
+	/// @return decimals
+	#[solidity(returns(_, token_name))]
+	fn info(&self) -> Result<(u8, String)> {
+		Ok((18, "Token".into()))
+	}
+	/// Tuple is returned as struct, same as before
+	fn info_struct(&self) -> Result<(u8, String)> {
+		Ok((18, "Token".into()))
+	}
+}
+
+fn call(call: TokenCall) -> Vec<u8> {
+	<Token as Callable<TokenCall>>::call(
+		&mut Token,
+		Msg {
+			call,
+			caller: Address::zero(),
+			value: U256::zero(),
+		},
+	)
+	.map_err(|post| post.0)
+	.unwrap()
+	.0
+}
+
+#[test]
+fn multiple_values_encoding() {
+	assert_eq!(
+		call(TokenCall::Account {
+			_owner: Address::zero()
+		}),
+		hex!(
+			"
+			0000000000000000000000000000000000000000000000000000000000000005
+			0000000000000000000000000000000000000000000000000000000000000001
+			"
+		)
+	);
+	// Dynamic values are referenced from top-level heads, as solidity does
+	assert_eq!(
+		call(TokenCall::Info),
+		hex!(
+			"
+			0000000000000000000000000000000000000000000000000000000000000012
+			0000000000000000000000000000000000000000000000000000000000000040
+			0000000000000000000000000000000000000000000000000000000000000005
+			546f6b656e000000000000000000000000000000000000000000000000000000
+			"
+		)
+	);
+	// While single tuple value is encoded as struct
+	assert_eq!(
+		call(TokenCall::InfoStruct),
+		hex!(
+			"
+			0000000000000000000000000000000000000000000000000000000000000020
+			0000000000000000000000000000000000000000000000000000000000000012
+			0000000000000000000000000000000000000000000000000000000000000040
+			0000000000000000000000000000000000000000000000000000000000000005
+			546f6b656e000000000000000000000000000000000000000000000000000000
+			"
+		)
+	);
+}
+
+struct LocalTransport;
+impl Transport for LocalTransport {
+	type Error = String;
+
+	fn call(&mut self, _to: Address, data: Vec<u8>, _value: U256) -> Result<Vec<u8>> {
+		let parsed = TokenCall::parse_full(&data)
+			.map_err(|e| e.to_string())?
+			.ok_or("unknown method")?;
+		Ok(call(parsed))
+	}
+}
+
+#[test]
+fn multiple_values_client() {
+	let mut client = TokenClient::new(LocalTransport, Address::zero());
+	assert_eq!(
+		client.account(Address::zero()).unwrap(),
+		(U256::from(5), true)
+	);
+	assert_eq!(client.info().unwrap(), (18, "Token".into()));
+	assert_eq!(client.info_struct().unwrap(), (18, "Token".into()));
+}
+
+#[test]
+fn multiple_values_descriptor() {
+	let returns = |i: usize| {
+		TokenCall::DESCRIPTOR.methods[i]
+			.returns
+			.iter()
+			.map(|r| (r.name, r.ty()))
+			.collect::<Vec<_>>()
+	};
+	assert_eq!(returns(0), [("balance", "uint256"), ("frozen", "bool")]);
+	assert_eq!(returns(1), [("", "uint8"), ("tokenName", "string")]);
+	assert_eq!(returns(2), [("", "(uint8,string)")]);
+}
+
+#[cfg(feature = "stubgen")]
+#[test]
+fn multiple_values_stub() {
+	let tc = evm_coder::solidity::TypeCollector::new();
+	TokenCall::generate_solidity_interface(&tc, false);
+	let out = tc.finish().join("");
+	assert!(
+		out.contains(
+			"function account(address owner) external view returns (uint256 balance, bool frozen);"
+		),
+		"{out}"
+	);
+	assert!(
+		out.contains("function info() external view returns (uint8, string memory tokenName);"),
+		"{out}"
+	);
+	assert!(
+		out.contains("function infoStruct() external view returns (Tuple"),
+		"{out}"
+	);
+	// Missing `@return` tags of named values are appended, existing ones are kept
+	assert!(
+		out.contains("\t/// @return balance Balance of owner\n\t/// @return frozen\n"),
+		"{out}"
+	);
+	assert!(
+		out.contains("\t/// @return decimals\n\t/// @return tokenName\n"),
+		"{out}"
+	);
+	assert_eq!(out.matches("@return balance").count(), 1, "{out}");
+}
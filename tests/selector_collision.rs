@@ -0,0 +1,53 @@
+use evm_coder::{dummy_contract, solidity_interface};
+
+type Result<T> = core::result::Result<T, String>;
+
+pub struct Contract;
+dummy_contract! {
+	macro_rules! Contract_result {...}
+	impl Contract for Contract {...}
+}
+
+#[solidity_interface(name = Base)]
+impl Contract {
+	fn base(&self) -> Result<()> {
+		Ok(())
+	}
+}
+
+#[solidity_interface(name = Left, is(Base))]
+impl Contract {
+	fn left(&self) -> Result<()> {
+		Ok(())
+	}
+}
+
+#[solidity_interface(name = Right, is(Base))]
+impl Contract {
+	fn right(&self) -> Result<()> {
+		Ok(())
+	}
+}
+
+// Base is inherited twice, which is not a collision
+#[solidity_interface(name = Diamond, is(Left, Right))]
+impl Contract {}
+
+#[test]
+fn diamond_inheritance_is_allowed() {
+	assert_eq!(DiamondCall::SELECTOR_COUNT, 4);
+	assert_eq!(DiamondCall::selector_at(0), LeftCall::LEFT);
+	assert_eq!(DiamondCall::selector_at(1), BaseCall::BASE);
+	assert_eq!(DiamondCall::selector_at(3), BaseCall::BASE);
+	assert_eq!(
+		DiamondCall::selector_name_at(3).as_str(),
+		Some("Base::base (base())")
+	);
+}
+
+#[test]
+fn selector_collisions() {
+	let t = trybuild::TestCases::new();
+	t.compile_fail("tests/build_failed/selector_collision_renamed.rs");
+	t.compile_fail("tests/build_failed/selector_collision_inherited.rs");
+}
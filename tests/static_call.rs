@@ -0,0 +1,153 @@
+use evm_coder::{
+	abi::AbiEncode, dummy_contract, solidity_interface, types::*, CallableView, ERC165Call,
+	STATIC_CALL_ERROR,
+};
+use primitive_types::U256;
+
+type Result<T> = core::result::Result<T, String>;
+
+#[derive(Default)]
+pub struct Counter {
+	value: u32,
+	inner: Inner,
+}
+dummy_contract! {
+	macro_rules! Counter_result {...}
+	impl Contract for Counter {...}
+}
+
+#[derive(Default)]
+pub struct Inner;
+dummy_contract! {
+	macro_rules! Inner_result {...}
+	impl Contract for Inner {...}
+}
+
+#[solidity_interface(name = InnerBase)]
+impl Inner {
+	fn inner_value(&self) -> Result<u8> {
+		Ok(7)
+	}
+}
+
+impl Counter {
+	fn inner_mut(&mut self) -> &mut Inner {
+		&mut self.inner
+	}
+	fn inner(&self) -> &Inner {
+		&self.inner
+	}
+}
+
+#[solidity_interface(name = CounterBase)]
+impl Counter {
+	fn base_value(&self) -> Result<u32> {
+		Ok(self.value)
+	}
+	fn reset(&mut self) -> Result<()> {
+		self.value = 0;
+		Ok(())
+	}
+}
+
+#[solidity_interface(name = Counter, is(
+	CounterBase,
+	InnerBase(via(inner_mut returns Inner, view = inner)),
+))]
+impl Counter {
+	fn value(&self) -> Result<u32> {
+		Ok(self.value)
+	}
+	fn max_value() -> Result<u32> {
+		Ok(u32::MAX)
+	}
+	fn increment(&mut self) -> Result<()> {
+		self.value += 1;
+		Ok(())
+	}
+}
+
+#[solidity_interface(name = CounterNoView, inline_is(
+	InnerBase(via(inner_mut returns Inner)),
+))]
+impl Counter {}
+
+fn call_view<C: evm_coder::Call>(counter: &Counter, call: C) -> Result<Vec<u8>>
+where
+	Counter: CallableView<C>,
+{
+	counter
+		.call_view(Msg {
+			call,
+			caller: Address::zero(),
+			value: U256::zero(),
+		})
+		.map(|post| post.0)
+		.map_err(|post| post.0)
+}
+
+#[test]
+fn view_methods_are_dispatched() {
+	let counter = Counter {
+		value: 5,
+		..Default::default()
+	};
+	assert_eq!(
+		call_view(&counter, CounterCall::Value).unwrap(),
+		5u32.abi_encode()
+	);
+	assert_eq!(
+		call_view(&counter, CounterCall::MaxValue).unwrap(),
+		u32::MAX.abi_encode()
+	);
+	assert_eq!(
+		call_view(
+			&counter,
+			CounterCall::CounterBase(CounterBaseCall::BaseValue)
+		)
+		.unwrap(),
+		5u32.abi_encode()
+	);
+	assert_eq!(
+		call_view(&counter, CounterCall::InnerBase(InnerBaseCall::InnerValue)).unwrap(),
+		7u8.abi_encode()
+	);
+	assert_eq!(
+		call_view(
+			&counter,
+			CounterCall::ERC165Call(
+				ERC165Call::SupportsInterface {
+					interface_id: CounterCall::interface_id(),
+				},
+				Default::default(),
+			)
+		)
+		.unwrap(),
+		true.abi_encode()
+	);
+}
+
+#[test]
+fn mutable_methods_are_rejected() {
+	let counter = Counter::default();
+	assert_eq!(
+		call_view(&counter, CounterCall::Increment).unwrap_err(),
+		STATIC_CALL_ERROR
+	);
+	assert_eq!(
+		call_view(&counter, CounterCall::CounterBase(CounterBaseCall::Reset)).unwrap_err(),
+		STATIC_CALL_ERROR
+	);
+}
+
+#[test]
+fn via_without_view_accessor_is_rejected() {
+	assert_eq!(
+		call_view(
+			&Counter::default(),
+			CounterNoViewCall::InnerBase(InnerBaseCall::InnerValue)
+		)
+		.unwrap_err(),
+		STATIC_CALL_ERROR
+	);
+}
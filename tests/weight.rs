@@ -0,0 +1,80 @@
+use evm_coder::{abi::AbiType, dummy_contract, solidity_interface, types::*, Weighted};
+use primitive_types::U256;
+
+type Result<T> = core::result::Result<T, String>;
+
+pub struct Contract;
+dummy_contract! {
+	macro_rules! Contract_result {...}
+	impl Contract for Contract {...}
+}
+
+#[solidity_interface(name = Weightless)]
+impl Contract {
+	fn weightless(&self) -> Result<()> {
+		Ok(())
+	}
+}
+
+#[solidity_interface(name = Base, weight = u64)]
+impl Contract {
+	#[weight(100 + *count as u64 * 10)]
+	fn mint(&mut self, _to: Address, count: u32) -> Result<u32> {
+		Ok(count)
+	}
+	fn balance(&self) -> Result<U256> {
+		Ok(U256::zero())
+	}
+}
+
+#[solidity_interface(name = Contract, is(Base, Weightless), weight = u64)]
+impl Contract {
+	#[weight(data.len() as u64)]
+	fn store(&mut self, data: Bytes) -> Result<U256> {
+		Ok(data.len().into())
+	}
+}
+
+#[test]
+fn weight_from_arguments() {
+	assert_eq!(
+		BaseCall::Mint {
+			_to: Address::zero(),
+			count: 3,
+		}
+		.weight(),
+		130
+	);
+	assert_eq!(
+		ContractCall::Store {
+			data: Bytes(Vec::from([0; 5])),
+		}
+		.weight(),
+		5
+	);
+}
+
+#[test]
+fn default_weight() {
+	assert_eq!(BaseCall::Balance.weight(), 0);
+	assert_eq!(
+		ContractCall::Weightless(WeightlessCall::Weightless).weight(),
+		0
+	);
+	assert_eq!(
+		<WeightlessCall as Weighted<u32>>::weight(&WeightlessCall::Weightless),
+		0
+	);
+}
+
+#[test]
+fn inherited_weight() {
+	assert_eq!(
+		ContractCall::Base(BaseCall::Mint {
+			_to: Address::zero(),
+			count: 1,
+		})
+		.weight(),
+		110
+	);
+}